@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+import random
+import timeit
+
+from qadence2_expressions import (
+    Expression,
+    compile_expression,
+    cos,
+    evaluate,
+    exp,
+    parameter,
+    sin,
+    sqrt,
+)
+
+# Compares the tree evaluation of expressions with their compiled stack machine form. Run with
+# `python benchmarks/bench_compile.py`. Each timing is the best of `REPEAT` runs of `NUMBER` calls.
+REPEAT = 5
+NUMBER = 2000
+
+
+def expressions() -> dict[str, Expression]:
+    x = parameter("x")
+    y = parameter("y")
+    theta = parameter("theta")
+
+    polynomial = sum((k + 1) * x**k * y ** (5 - k) for k in range(6))
+
+    return {
+        "small": theta * x + sin(x),
+        "polynomial": polynomial,
+        "nested": exp(-(x**2) / 2) * cos(theta * y) / sqrt(theta) + sin(x * y) ** 2,
+    }
+
+
+def main() -> None:
+    rng = random.Random(0)
+
+    print(f"{'expression':<12} {'evaluate':>12} {'compiled':>12} {'speedup':>8}")
+    for name, expr in expressions().items():
+        compiled = compile_expression(expr)
+        params = [rng.uniform(0.5, 2.0) for _ in compiled.parameters]
+        bindings = dict(zip(compiled.parameters, params))
+
+        tree = min(timeit.repeat(lambda: evaluate(expr, bindings), number=NUMBER, repeat=REPEAT))
+        flat = min(timeit.repeat(lambda: compiled(params), number=NUMBER, repeat=REPEAT))
+
+        tree_us = tree / NUMBER * 1e6
+        flat_us = flat / NUMBER * 1e6
+        print(f"{name:<12} {tree_us:>10.2f}us {flat_us:>10.2f}us {tree / flat:>7.1f}x")
+
+
+if __name__ == "__main__":
+    main()
@@ -1,17 +1,45 @@
 from __future__ import annotations
 
 from importlib import import_module
+from typing import Any
 
-from .collect import collect_operators
+from .assumptions import SymbolAssumptions, simplify_with_assumptions
+from .collect import as_linear_combination, collect_operators, group_by_support
 from .core import *
+from .decomposition import GateLibrary, decompose
+from .derivative import derivative, linearize_around, parameter_shift
+from .evaluation import (
+    CompiledExpression,
+    EvalContext,
+    compile_closure,
+    compile_expression,
+    eval_cost,
+    evaluate,
+    evaluate_over_time,
+    evaluate_vec,
+    parameters,
+    partial_evaluate,
+    probably_equal,
+    substitute_value,
+)
 from .functions import (
+    atan,
+    cbrt,
+    conj,
     cos,
+    cosh,
     exp,
+    exp2,
     log,
+    log10,
     sin,
+    sinh,
+    split_real_imag,
     sqrt,
+    tanh,
 )
-from .ircompiler import compile_to_model
+from .hermitian import is_hermitian
+from .interval import Interval, evaluate_interval
 from .operators import (
     CZ,
     H,
@@ -31,28 +59,198 @@ from .operators import (
     Y,
     Z,
 )
-from .replace import prod, replace
+from .parser import ParseError, parse
+from .polynomial import (
+    as_affine,
+    coefficients,
+    degree,
+    factor_common,
+    is_identically_one,
+    is_identically_zero,
+    monomials,
+    order_terms_by_degree,
+    to_horner,
+    to_quadratic_coefficients,
+    to_sop,
+    total_degree,
+)
+from .printing import to_sympy_string
+from .replace import (
+    prefix_symbols,
+    prod,
+    reindex,
+    rename_symbols,
+    replace,
+    replace_operator,
+    replace_symbols,
+    subs,
+    substitute_many_exprs,
+)
+from .serialization import from_dict, from_json, to_dict, to_json
+from .simplify import (
+    collapse_singletons,
+    combine_like_terms,
+    drop_small_terms,
+    equals_up_to_scalar,
+    expand_integer_power,
+    fold_powers,
+    fold_signs,
+    prune_zeros,
+    rationalize_denominators,
+    simplify_trig,
+)
+from .summation import (
+    expand_sum,
+    kronecker_delta,
+    levi_civita,
+    reduce_index_functions,
+    summation,
+)
+from .tensor_network import Tensor, TensorNetwork, to_tensor_network
+from .validation import (
+    non_finite_paths,
+    replace_non_finite,
+    validate,
+    validate_wellformed,
+)
+
+# The compilation to the IR is only available with the `ir` extra.
+try:
+    from .ircompiler import compile_to_model
+except ModuleNotFoundError as error:
+    if not (error.name or "").startswith("qadence2_ir"):
+        raise
+
+    _ir_import_error = error
+
+    def compile_to_model(expr: Expression) -> Any:  # type: ignore [misc]
+        """Requires the `ir` extra, `pip install qadence2-expressions[ir]`."""
+        raise ImportError(
+            "The compilation to the IR requires the `qadence2-ir` package, install it with "
+            "`pip install qadence2-expressions[ir]`."
+        ) from _ir_import_error
+
+# The dense matrices of operators are only available with the `numpy` extra.
+try:
+    from .matrix import to_matrix
+except ModuleNotFoundError as error:
+    if error.name != "numpy":
+        raise
+
+    _numpy_import_error = error
+
+    def to_matrix(expr: Expression, num_qubits: int) -> Any:  # type: ignore [misc]
+        """Requires the `numpy` extra, `pip install qadence2-expressions[numpy]`."""
+        raise ImportError(
+            "The dense matrices require the `numpy` package, install it with "
+            "`pip install qadence2-expressions[numpy]`."
+        ) from _numpy_import_error
+
 
 __all__ = [
+    "as_affine",
+    "as_linear_combination",
+    "atan",
+    "cbrt",
+    "check_depth",
+    "coefficients",
+    "collapse_singletons",
     "cos",
+    "cosh",
     "collect_operators",
+    "combine_like_terms",
+    "compile_closure",
+    "compile_expression",
     "compile_to_model",
+    "conj",
+    "CompiledExpression",
     "CZ",
+    "decompose",
+    "degree",
+    "DepthExceededError",
+    "derivative",
+    "drop_small_terms",
+    "equals_up_to_scalar",
+    "eval_cost",
+    "EvalContext",
+    "evaluate",
+    "evaluate_interval",
+    "evaluate_over_time",
+    "evaluate_vec",
     "exp",
+    "exp2",
+    "expand_integer_power",
+    "expand_sum",
+    "factor_common",
+    "fold_powers",
+    "fold_signs",
     "FreeEvolution",
+    "from_dict",
+    "from_json",
+    "GateLibrary",
+    "group_by_support",
     "H",
+    "Interval",
+    "is_hermitian",
+    "is_identically_one",
+    "is_identically_zero",
+    "kronecker_delta",
+    "levi_civita",
+    "linearize_around",
     "log",
+    "log10",
+    "monomials",
     "NativeDrive",
     "PiecewiseDrive",
+    "non_finite_paths",
     "NOT",
+    "order_terms_by_degree",
+    "parameter_shift",
+    "parameters",
+    "parse",
+    "ParseError",
+    "partial_evaluate",
+    "prefix_symbols",
+    "probably_equal",
     "prod",
+    "prune_zeros",
+    "rationalize_denominators",
+    "reduce_index_functions",
+    "reindex",
+    "rename_symbols",
     "replace",
+    "replace_non_finite",
+    "replace_operator",
+    "replace_symbols",
     "RX",
     "RY",
     "RZ",
     "SWAP",
+    "simplify_trig",
+    "simplify_with_assumptions",
     "sin",
+    "sinh",
+    "split_real_imag",
     "sqrt",
+    "SymbolAssumptions",
+    "subs",
+    "substitute_many_exprs",
+    "substitute_value",
+    "summation",
+    "tanh",
+    "Tensor",
+    "TensorNetwork",
+    "to_dict",
+    "to_horner",
+    "to_json",
+    "to_matrix",
+    "to_quadratic_coefficients",
+    "to_sop",
+    "to_tensor_network",
+    "to_sympy_string",
+    "total_degree",
+    "validate",
+    "validate_wellformed",
     "X",
     "Xm",
     "Xp",
@@ -0,0 +1,170 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Mapping
+
+from .core.expression import Expression
+from .replace import evaluate_node
+from .simplify import rewrite_bottom_up
+
+
+@dataclass(frozen=True)
+class SymbolAssumptions:
+    """Properties assumed for the values of a symbol.
+
+    A positive or an integer symbol is also real. The flags are only used by the rewrites that
+    depend on them, see `simplify_with_assumptions`.
+    """
+
+    real: bool = False
+    positive: bool = False
+    integer: bool = False
+
+    @property
+    def is_real(self) -> bool:
+        return self.real or self.positive or self.integer
+
+
+# Assumptions per symbol name. The symbols not listed have no assumptions, i.e., they can take
+# any complex value.
+Assumptions = Mapping[str, SymbolAssumptions]
+
+
+def simplify_with_assumptions(expr: Expression, assumptions: Assumptions) -> Expression:
+    """Apply the simplifications that are only valid under the given `assumptions`.
+
+    The rules, applied when the assumptions hold, are
+
+    - `(x^a)^b = x^(a * b)` when `x` is positive or `b` is an integer,
+    - `(x^n)^(1/n) = |x|` for even `n`, when `x` is real. Odd roots of negative numbers are
+      complex with the principal branch, e.g., `((-8)^3)^(1/3) = 4 + 6.93j`, so they are only
+      merged when `x` is positive, by the previous rule,
+    - `|x| = x` when `x` is positive,
+    - `log(exp(x)) = x` when `x` is real, and `exp(log(x)) = x` when `x` is positive.
+
+    The assumptions are passed explicitly instead of being stored in the environment, so the
+    same expression can be simplified under different assumptions. Note that the `**` operator
+    merges nested numerical powers regardless of the base, so build the expression without
+    evaluation to keep them apart, e.g., `Expression.pow(Expression.pow(x, value(2)), value(0.5))`.
+
+    Example:
+    ```
+    >>> sqrt_x2 = Expression.pow(Expression.pow(x, value(2)), value(0.5))
+    >>> simplify_with_assumptions(sqrt_x2, {"x": SymbolAssumptions(positive=True)})
+    x
+    >>> simplify_with_assumptions(sqrt_x2, {"x": SymbolAssumptions(real=True)})
+    |x|
+    >>> simplify_with_assumptions(sqrt_x2, {})
+    x ^ 2.0 ^ 0.5
+    ```
+    """
+
+    def rule(expr: Expression) -> Expression:
+        if expr.is_function and len(expr.args) == 2:
+            name, arg = expr[0][0], expr[1]
+
+            if name == "abs" and is_positive(arg, assumptions):
+                return arg
+
+            if name == "log" and _is_exp(arg) and is_real(arg[1], assumptions):
+                return arg[1]  # type: ignore [no-any-return]
+
+            return expr
+
+        if not expr.is_power:
+            return evaluate_node(expr)
+
+        base, power = expr.args
+
+        if power.is_one:
+            return base  # type: ignore [no-any-return]
+
+        if _is_exp(expr) and power.is_function and power[0][0] == "log" and len(power.args) == 2:
+            if is_positive(power[1], assumptions):
+                return power[1]  # type: ignore [no-any-return]
+            return expr
+
+        if not base.is_power:
+            return evaluate_node(expr)
+
+        inner_base, inner_power = base.args
+
+        if is_positive(inner_base, assumptions) or is_integer(power, assumptions):
+            return inner_base ** (inner_power * power)  # type: ignore [no-any-return]
+
+        # Roots of even powers of real numbers, `(x^n)^(1/n)`.
+        if (
+            inner_power.is_value
+            and power.is_value
+            and is_integer(inner_power, assumptions)
+            and inner_power[0] % 2 == 0
+            and inner_power[0] * power[0] == 1
+            and is_real(inner_base, assumptions)
+        ):
+            return abs(inner_base)
+
+        # The `**` operator would merge the exponents.
+        return expr
+
+    return rewrite_bottom_up(expr, rule)
+
+
+def is_real(expr: Expression, assumptions: Assumptions) -> bool:
+    """Returns true if the expression is known to be real under the `assumptions`."""
+
+    if expr.is_value:
+        return not isinstance(expr[0], complex)
+
+    if expr.is_symbol:
+        flags = assumptions.get(expr[0])
+        return flags is not None and flags.is_real
+
+    if expr.is_addition or expr.is_multiplication:
+        return expr.subspace is None and all(is_real(arg, assumptions) for arg in expr.args)
+
+    if expr.is_power:
+        base, power = expr.args
+        return (is_positive(base, assumptions) and is_real(power, assumptions)) or (
+            is_real(base, assumptions) and is_integer(power, assumptions)
+        )
+
+    return False
+
+
+def is_positive(expr: Expression, assumptions: Assumptions) -> bool:
+    """Returns true if the expression is known to be positive under the `assumptions`."""
+
+    if expr.is_value:
+        return not isinstance(expr[0], complex) and expr[0] > 0
+
+    if expr.is_symbol:
+        flags = assumptions.get(expr[0])
+        return flags is not None and flags.positive
+
+    if expr.is_addition or expr.is_multiplication:
+        return expr.subspace is None and all(is_positive(arg, assumptions) for arg in expr.args)
+
+    if expr.is_power:
+        return is_positive(expr[0], assumptions) and is_real(expr[1], assumptions)
+
+    return False
+
+
+def is_integer(expr: Expression, assumptions: Assumptions) -> bool:
+    """Returns true if the expression is known to be an integer under the `assumptions`."""
+
+    if expr.is_value:
+        return not isinstance(expr[0], complex) and float(expr[0]).is_integer()
+
+    if expr.is_symbol:
+        flags = assumptions.get(expr[0])
+        return flags is not None and flags.integer
+
+    if expr.is_addition or expr.is_multiplication:
+        return expr.subspace is None and all(is_integer(arg, assumptions) for arg in expr.args)
+
+    return False
+
+
+def _is_exp(expr: Expression) -> bool:
+    return expr.is_power and expr[0].is_symbol and expr[0][0] == "E"
@@ -1,8 +1,12 @@
 from __future__ import annotations
 
 from functools import reduce
+from typing import Callable
 
 from .core.expression import Expression
+from .core.support import Support
+from .core.utils import Numeric
+from .polynomial import to_sop
 
 
 def collect_operators(polynomial: Expression) -> dict[Expression, Expression]:
@@ -32,3 +36,90 @@ def _collect_operators_core(
         acc[term] = acc.get(expr, Expression.zero()) + coef
 
     return acc
+
+
+def as_linear_combination(
+    expr: Expression, is_basis: Callable[[Expression], bool]
+) -> list[tuple[Numeric, Expression]]:
+    """Decompose an expression into a linear combination `sum_k c_k * P_k` of basis operators.
+
+    The expression is expanded into a sum of products, and each term is split into its numerical
+    coefficient and the remaining factor, which must be accepted by `is_basis`. A numerical term
+    is paired with the identity, `1`, which must be accepted by `is_basis` as well.
+
+    Example:
+    ```
+    >>> as_linear_combination(2 * X(0) + 3 * Z(1), lambda op: op.is_quantum_operator)
+    [(2.0, X[0]), (3.0, Z[1])]
+    ```
+
+    Raises:
+        ValueError: If a term is not a numerical coefficient times a basis operator, e.g., if it
+            has a symbolic coefficient.
+    """
+
+    expanded = to_sop(expr)
+    terms = expanded.args if expanded.is_addition else (expanded,)
+
+    result = []
+    for term in terms:
+        if term.is_value:
+            coefficient, operator = term[0], Expression.one()
+        elif term.is_multiplication and term[0].is_value:
+            coefficient = term[0][0]
+            if len(term.args) == 2:
+                operator = term[1]
+            else:
+                operator = Expression(term.head, *term[1:], **term.attrs)
+        else:
+            coefficient, operator = 1.0, term
+
+        if not is_basis(operator):
+            raise ValueError(
+                f"The term {term} is not a numerical coefficient times a basis operator."
+            )
+
+        result.append((coefficient, operator))
+
+    return result
+
+
+def group_by_support(expr: Expression) -> list[list[Expression]]:
+    """Partition the terms of a sum into groups of terms acting on disjoint qubits.
+
+    The terms are assigned greedily, in order, to the first group where they don't overlap with
+    any other term, so the terms of a group can be applied in parallel. Terms without quantum
+    operators fit in any group, while terms acting on all the qubits are placed alone. An
+    expression other than a sum is a single group with a single term.
+
+    Example:
+    ```
+    >>> group_by_support(X(0) + Z(1) + Y(0))
+    [[X[0], Z[1]], [Y[0]]]
+    ```
+    """
+
+    terms = expr.args if expr.is_addition else (expr,)
+    groups: list[list[Expression]] = []
+    supports: list[list[Support]] = []
+
+    for term in terms:
+        support = term.subspace
+        i = next(
+            (
+                i
+                for i, group_supports in enumerate(supports)
+                if support is None or not any(support.overlap_with(s) for s in group_supports)
+            ),
+            len(groups),
+        )
+
+        if i == len(groups):
+            groups.append([])
+            supports.append([])
+
+        groups[i].append(term)
+        if support is not None:
+            supports[i].append(support)
+
+    return groups
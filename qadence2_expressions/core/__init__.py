@@ -1,14 +1,19 @@
 from __future__ import annotations
 
+from .arena import ExprArena
 from .constructors import (
+    Product,
+    Sum,
     array_parameter,
     array_variable,
     function,
+    indexed,
     parameter,
     parametric_operator,
     projector,
     promote,
     symbol,
+    symbols,
     unitary_hermitian_operator,
     value,
     variable,
@@ -30,9 +35,15 @@ from .environment import (
     set_number_qubits,
     set_qubits_positions,
 )
-from .expression import Expression
+from .expression import Expression, FormatOptions, noncommutative_multiplication
 from .support import Support
-from .utils import Numeric
+from .utils import (
+    DepthExceededError,
+    Numeric,
+    check_depth,
+    checked_pow,
+    numeric_sort_key,
+)
 
 __all__ = [
     "add_grid_options",
@@ -41,6 +52,8 @@ __all__ = [
     "array_parameter",
     "array_variable",
     "Expression",
+    "ExprArena",
+    "FormatOptions",
     "function",
     "get_grid_options",
     "get_grid_scale",
@@ -49,8 +62,11 @@ __all__ = [
     "get_qpu_directives",
     "get_qubits_positions",
     "get_settings",
+    "indexed",
+    "noncommutative_multiplication",
     "parameter",
     "parametric_operator",
+    "Product",
     "projector",
     "promote",
     "reset_ir_options",
@@ -58,10 +74,16 @@ __all__ = [
     "set_grid_type",
     "set_number_qubits",
     "set_qubits_positions",
+    "Sum",
     "Support",
     "symbol",
+    "symbols",
     "unitary_hermitian_operator",
     "value",
     "variable",
+    "DepthExceededError",
     "Numeric",
+    "check_depth",
+    "checked_pow",
+    "numeric_sort_key",
 ]
@@ -0,0 +1,86 @@
+from __future__ import annotations
+
+from collections import Counter
+from typing import Any, Hashable
+
+from .expression import Expression
+
+
+class ExprArena:
+    """Builds expressions sharing a single instance for each distinct subexpression.
+
+    Interning the nodes, also known as hash-consing, saves memory when the same subexpressions
+    appear many times, and allows comparing the interned nodes with `is` instead of a structural
+    comparison. The nodes are built without evaluation, like `Expression(head, *args, **attrs)`.
+
+    Each arena keeps its own nodes, and they are only shared with the expressions built or
+    interned by the same arena. The nodes are kept alive as long as the arena is.
+
+    Unlike `==`, the interning distinguishes the types of the numbers, so `value(1)` and
+    `value(1 + 0j)`, or the expressions holding them, are kept as separate nodes.
+
+    Example:
+    ```
+    >>> arena = ExprArena()
+    >>> lhs = arena.build(Expression.Tag.ADD, symbol("x"), value(1))
+    >>> rhs = arena.intern(Expression.add(symbol("x"), value(1)))
+    >>> lhs is rhs
+    True
+    ```
+    """
+
+    def __init__(self) -> None:
+        # The nodes are indexed by themselves and the signature of their arguments, see `_key`.
+        self._nodes: dict[tuple[Expression, Hashable], Expression] = {}
+        self._ids: dict[int, Expression] = {}
+
+    def __len__(self) -> int:
+        return len(self._nodes)
+
+    def __contains__(self, expr: object) -> bool:
+        return isinstance(expr, Expression) and self._find(expr) is not None
+
+    def build(self, head: Expression.Tag, *args: Any, **attrs: Any) -> Expression:
+        """Returns the interned node `Expression(head, *args, **attrs)`, interning its arguments."""
+
+        args = tuple(self.intern(arg) if isinstance(arg, Expression) else arg for arg in args)
+        node = Expression(head, *args, **attrs)
+
+        key = self._key(node)
+        if key not in self._nodes:
+            self._nodes[key] = node
+            self._ids[id(node)] = node
+        return self._nodes[key]
+
+    def intern(self, expr: Expression) -> Expression:
+        """Returns the instance shared by all the expressions of the arena equal to `expr` and
+        holding numbers of the same types.
+        """
+
+        if self._ids.get(id(expr)) is expr:
+            return expr
+
+        return self.build(expr.head, *expr.args, **expr.attrs)
+
+    def _find(self, expr: Expression) -> Expression | None:
+        if self._ids.get(id(expr)) is expr:
+            return expr
+
+        args = []
+        for arg in expr.args:
+            if isinstance(arg, Expression):
+                arg = self._find(arg)
+                if arg is None:
+                    return None
+            args.append(arg)
+
+        return self._nodes.get(self._key(Expression(expr.head, *args, **expr.attrs)))
+
+    @staticmethod
+    def _key(node: Expression) -> tuple[Expression, Hashable]:
+        # The subexpressions are already interned, so their identity tells the types of the
+        # numbers they hold. The other arguments, like the number of a value, add their type.
+        signature = [id(arg) if isinstance(arg, Expression) else type(arg) for arg in node.args]
+        if node.is_commutative:
+            return node, frozenset(Counter(signature).items())
+        return node, tuple(signature)
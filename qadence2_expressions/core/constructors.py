@@ -1,6 +1,8 @@
 from __future__ import annotations
 
-from typing import Any, Callable
+from dataclasses import dataclass
+from functools import reduce
+from typing import Any, Callable, Sequence
 
 from .environment import Environment
 from .expression import Expression
@@ -30,17 +32,53 @@ def value(x: Numeric) -> Expression:
     return Expression.value(x)
 
 
-def promote(x: Expression | Numeric) -> Expression:
+@dataclass(frozen=True)
+class Sum:
+    """Marks a sequence of terms to be added up when promoted to an expression.
+
+    Example:
+    ```
+    >>> promote(Sum([x, 2 * y, 1]))
+    1.0 + x + 2.0 * y
+    ```
+    """
+
+    terms: Sequence[Expression | Numeric]
+
+
+@dataclass(frozen=True)
+class Product:
+    """Marks a sequence of factors to be multiplied, in order, when promoted to an expression.
+
+    Example:
+    ```
+    >>> promote(Product([2, x, X(0)]))
+    2.0 * x * X[0]
+    ```
+    """
+
+    factors: Sequence[Expression | Numeric]
+
+
+def promote(x: Expression | Numeric | Sum | Product) -> Expression:
     """Type cast inputs as value type expressions.
 
     Args:
-        x (Expression | Numeric): A valid expression or numerical value.
-         Numerical values are converted into `Value(x)` expressions.
+        x (Expression | Numeric | Sum | Product): A valid expression or numerical value.
+         Numerical values are converted into `Value(x)` expressions. The terms of a `Sum` and the
+         factors of a `Product` are combined with the arithmetic operators, evaluating the result
+         as a single flat expression.
 
     Returns:
         Expression: A value type or expression.
     """
 
+    if isinstance(x, Sum):
+        return reduce(lambda acc, term: acc + promote(term), x.terms, Expression.zero())
+
+    if isinstance(x, Product):
+        return reduce(lambda acc, factor: acc * promote(factor), x.factors, Expression.one())
+
     return value(x) if not isinstance(x, Expression) else x
 
 
@@ -66,6 +104,48 @@ def symbol(identifier: str, **attributes: Any) -> Expression:
     return Expression.symbol(identifier, **attributes)
 
 
+def symbols(names: str, **attributes: Any) -> tuple[Expression, ...]:
+    """Create several symbols at once, to build expressions inline with the Python operators.
+
+        x, y = symbols("x y")
+        2 * x + y**2
+
+    Args:
+        names (str): Symbols' names separated by spaces or commas.
+
+    Kwargs:
+        attributes (Any): Keywords used as flags for compilation, shared by all the symbols.
+
+    Returns:
+        tuple[Expression, ...]: The symbol expressions in the same order as the names.
+
+    Raises:
+        SyntaxError: If any of the names is expression protected.
+    """
+
+    return tuple(symbol(name, **attributes) for name in names.replace(",", " ").split())
+
+
+def indexed(name: str, index: Expression | int) -> Expression:
+    """An element of an array of inputs.
+
+    Args:
+        name (str): Array's name.
+        index (Expression | int): Element's index, either an integer or a symbolic expression.
+
+    Returns:
+        Expression: A symbol expression with an index.
+
+    Raises:
+        SyntaxError: If the name is expression protected.
+    """
+
+    if name in Environment.protected:
+        raise SyntaxError(f"'{name}' is protected.")
+
+    return Expression.indexed(name, index)
+
+
 def parameter(name: str) -> Expression:
     """A non-trainable input.
 
@@ -120,18 +200,25 @@ def array_variable(name: str, size: int) -> Expression:
     return symbol(name, trainable=True, size=size)
 
 
-def function(name: str, *args: Any) -> Expression:
+def function(name: str, *args: Any, **kwargs: Expression | Numeric) -> Expression:
     """Symbolic representation of a function.
 
     Args:
         name (str): Function's name.
         args (Any): Remaining function arguments.
+        kwargs (Expression | Numeric): Named function arguments, e.g.,
+            `function("gaussian", t, sigma=s)`. Numerical values are converted into expressions.
 
     Returns:
-        Expression: A function expression.
+        Expression: A function expression, or undefined if any of the arguments is undefined.
     """
 
-    return Expression.function(name, *args)
+    kwargs = {k: promote(v) for k, v in kwargs.items()}
+
+    if any(isinstance(arg, Expression) and arg.is_undefined for arg in (*args, *kwargs.values())):
+        return Expression.undefined()
+
+    return Expression.function(name, *args, **kwargs)
 
 
 def unitary_hermitian_operator(name: str) -> Callable:
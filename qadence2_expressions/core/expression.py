@@ -1,13 +1,49 @@
 from __future__ import annotations
 
+import cmath
+import sys
 import warnings
+from collections import Counter
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass
+from decimal import Decimal
 from enum import Enum
 from functools import cached_property, reduce
-from re import sub
-from typing import Any
+from operator import floordiv, mod
+from typing import Any, Iterable, Iterator, Literal
 
 from .support import Support
-from .utils import Numeric
+from .utils import (
+    IMAGINARY_UNIT_POWERS,
+    Numeric,
+    imaginary_unit_exponent,
+    numeric_pow,
+    numeric_sort_key,
+)
+
+# Whether the multiplication reorders and combines its factors. Use the
+# `noncommutative_multiplication` context manager to disable it.
+COMMUTATIVE_MULTIPLICATION: ContextVar[bool] = ContextVar(
+    "commutative_multiplication", default=True
+)
+
+# Symbols representing the expression tags, indexed by the tag name.
+OPERATOR_SYMBOLS = {"FN": "call", "ADD": "+", "MUL": "*", "KRON": "@", "POW": "^"}
+
+# Weights of the operations in `Expression.complexity_score`, indexed by the tag name. Powers and
+# function calls are costlier to evaluate and harder to simplify than the other operations.
+COMPLEXITY_WEIGHTS = {"FN": 3.0, "QUANTUM_OP": 1.0, "ADD": 1.0, "MUL": 1.0, "KRON": 1.0, "POW": 3.0}
+
+# Weight of the depth of the tree in `Expression.complexity_score`.
+DEPTH_WEIGHT = 2.0
+
+# Integer division functions, `x // y` and `x % y`, indexed by their function names, with their
+# operator symbols. Their results follow Python's floor division for real numbers.
+INTEGER_DIVISIONS = {
+    "floordiv": (floordiv, "//"),
+    "mod": (mod, "%"),
+}
 
 
 class Expression:
@@ -36,11 +72,79 @@ class Expression:
         KRON = "KroneckerProduct"
         POW = "Power"
 
+        def __str__(self) -> str:
+            """Operations are represented by their operator symbol, `+`, `*`, `@`, `^`, and
+            functions by `call`. Identifiers use the tag value.
+            """
+            return OPERATOR_SYMBOLS.get(self.name, self.value)
+
+        @classmethod
+        def from_str(cls, symbol: str) -> Expression.Tag:
+            """Returns the tag represented by an operator `symbol` or tag value.
+
+            Example:
+            ```
+            >>> Expression.Tag.from_str("*")
+            <Tag.MUL: 'Multiply'>
+            ```
+
+            Raises:
+                ValueError: If the symbol doesn't represent any tag.
+            """
+
+            for tag in cls:
+                if symbol in (str(tag), tag.value):
+                    return tag
+
+            raise ValueError(f"Unknown operator '{symbol}'.")
+
+        def identity(self) -> Expression | None:
+            """Returns the identity element of the operation, if any.
+
+            Example:
+            ```
+            >>> Expression.Tag.ADD.identity()
+            0.0
+            >>> Expression.Tag.POW.identity()
+            None
+            ```
+            """
+
+            if self == Expression.Tag.ADD:
+                return Expression.zero()
+
+            if self in (Expression.Tag.MUL, Expression.Tag.KRON):
+                return Expression.one()
+
+            # Identifiers are not operations, and the power has no identity on the left side.
+            return None
+
+        def absorbing(self) -> Expression | None:
+            """Returns the absorbing element of the operation, if any.
+
+            Example:
+            ```
+            >>> Expression.Tag.MUL.absorbing()
+            0.0
+            >>> Expression.Tag.ADD.absorbing()
+            None
+            ```
+            """
+
+            if self in (Expression.Tag.MUL, Expression.Tag.KRON):
+                return Expression.zero()
+
+            return None
+
     def __init__(self, head: Expression.Tag, *args: Any, **attributes: Any) -> None:
         self.head = head
         self.args = args
         self.attrs = attributes
 
+        # Expressions are immutable, so their hash is computed once, on first use, and reused by
+        # the enclosing expressions. This keeps hashing linear on expressions sharing subtrees.
+        self._hash: int | None = None
+
     # Constructors
     @classmethod
     def value(cls, x: Numeric) -> Expression:
@@ -59,6 +163,9 @@ class Expression:
     def zero(cls) -> Expression:
         """Used to represent both, the numerical value `0` and the null operator.
 
+        It is also the natural default for expression fields, e.g., in dataclasses,
+        `field(default_factory=Expression.zero)`.
+
         Returns:
             An `Value(0)` expression.
         """
@@ -73,6 +180,19 @@ class Expression:
         """
         return cls.value(1)
 
+    @classmethod
+    def undefined(cls) -> Expression:
+        """Used to represent the result of indeterminate operations, like `0 / 0` or `inf - inf`.
+
+        Any operation involving an undefined expression is undefined, and its numerical
+        evaluation raises an error.
+
+        Returns:
+            A `Symbol('undefined')` expression marked by the `undefined` attribute, displayed as
+            `undefined`. Symbols named `undefined` without the attribute are ordinary symbols.
+        """
+        return cls.symbol("undefined", undefined=True)
+
     @classmethod
     def symbol(cls, identifier: str, **attributes: Any) -> Expression:
         """Create a symbol from the identifier.
@@ -90,20 +210,52 @@ class Expression:
         return cls(cls.Tag.SYMBOL, identifier, **attributes)
 
     @classmethod
-    def function(cls, name: str, *args: Any) -> Expression:
+    def indexed(cls, name: str, index: Expression | int, **attributes: Any) -> Expression:
+        """Create an element of the array symbol `name`.
+
+        The `index` can be an integer or an expression, e.g., a symbol used as summation index.
+        Integer-valued expressions are converted to integers.
+
+            Expression.indexed("x", 2) => x[2]
+
+        Returns:
+            A `Symbol('name', index=index)` expression.
+        """
+
+        if (
+            isinstance(index, Expression)
+            and index.is_value
+            and not isinstance(index[0], complex)
+            and float(index[0]).is_integer()
+        ):
+            index = int(index[0])
+
+        return cls(cls.Tag.SYMBOL, name, index=index, **attributes)
+
+    @classmethod
+    def function(cls, name: str, *args: Any, **kwargs: Any) -> Expression:
         """
         Symbolic representation of a function. The `name` indicates the function identifier, the
         remaining arguments are used as the function arguments.
 
             Expression.function("sin", 1.57) => sin(1.57)
+            Expression.function("gaussian", t, mean=0, sigma=1) => gaussian(t, mean=0, sigma=1)
 
         Args:
             name: The function name.
             args: The arguments to be passed to the function.
+            kwargs: The named arguments to be passed to the function. They are stored after the
+                positional arguments, and their names in the `keywords` attribute.
 
         Returns:
             A `Function(Symbol('name'), args...)` expression.
         """
+
+        if kwargs:
+            return cls(
+                cls.Tag.FN, cls.symbol(name), *args, *kwargs.values(), keywords=tuple(kwargs)
+            )
+
         return cls(cls.Tag.FN, cls.symbol(name), *args)
 
     @classmethod
@@ -179,6 +331,118 @@ class Expression:
 
         return cls(cls.Tag.POW, base, power)
 
+    # Checked constructors
+    @classmethod
+    def add_node(cls, *args: Expression) -> Expression:
+        """Same as `Expression.add` but validating the arguments.
+
+        Raises:
+            SyntaxError: If less than two terms are provided.
+            TypeError: If any of the terms is not an expression.
+        """
+
+        cls._check_operands("addition", args, min_arity=2)
+        return cls.add(*args)
+
+    @classmethod
+    def mul_node(cls, *args: Expression) -> Expression:
+        """Same as `Expression.mul` but validating the arguments.
+
+        Raises:
+            SyntaxError: If less than two factors are provided.
+            TypeError: If any of the factors is not an expression.
+        """
+
+        cls._check_operands("multiplication", args, min_arity=2)
+        return cls.mul(*args)
+
+    @classmethod
+    def kron_node(cls, *args: Expression) -> Expression:
+        """Same as `Expression.kron` but validating the arguments.
+
+        Raises:
+            SyntaxError: If less than two factors are provided or any of them is not a quantum
+                operator.
+            TypeError: If any of the factors is not an expression.
+        """
+
+        cls._check_operands("Kronecker product", args, min_arity=2)
+        if not all(arg.is_quantum_operator for arg in args):
+            raise SyntaxError("Kronecker product is only defined for quantum operators.")
+        return cls.kron(*args)
+
+    @classmethod
+    def pow_node(cls, base: Expression, power: Expression) -> Expression:
+        """Same as `Expression.pow` but validating the arguments.
+
+        Raises:
+            TypeError: If the base or the power is not an expression.
+        """
+
+        cls._check_operands("power", (base, power), min_arity=2)
+        return cls.pow(base, power)
+
+    @classmethod
+    def function_node(cls, name: str, *args: Expression) -> Expression:
+        """Same as `Expression.function` but validating the arguments.
+
+        Raises:
+            SyntaxError: If the name is not a valid identifier.
+            TypeError: If any of the arguments is not an expression.
+        """
+
+        if not (isinstance(name, str) and name.isidentifier()):
+            raise SyntaxError(f"'{name}' is not a valid function name.")
+
+        cls._check_operands("function", args, min_arity=0)
+        return cls.function(name, *args)
+
+    @classmethod
+    def from_op(cls, head: Expression.Tag, args: Iterable[Expression]) -> Expression:
+        """Build an operation node from a collection of arguments, validated as in the checked
+        constructors.
+
+        Example:
+        ```
+        >>> Expression.from_op(Expression.Tag.ADD, [x, y, z])
+        x + y + z
+        ```
+
+        Raises:
+            SyntaxError: If the number of arguments is invalid for the operation.
+            TypeError: If any of the arguments is not an expression.
+            ValueError: If `head` is not one of `ADD`, `MUL`, `KRON`, or `POW`.
+        """
+
+        args = tuple(args)
+
+        if head == cls.Tag.ADD:
+            return cls.add_node(*args)
+
+        if head == cls.Tag.MUL:
+            return cls.mul_node(*args)
+
+        if head == cls.Tag.KRON:
+            return cls.kron_node(*args)
+
+        if head == cls.Tag.POW:
+            if len(args) != 2:
+                raise SyntaxError(f"The power requires exactly 2 arguments, got {len(args)}.")
+            return cls.pow_node(*args)
+
+        raise ValueError(f"{head} is not an operation, use its own constructor instead.")
+
+    @staticmethod
+    def _check_operands(operation: str, args: tuple[Any, ...], min_arity: int) -> None:
+        if len(args) < min_arity:
+            raise SyntaxError(
+                f"The {operation} requires at least {min_arity} arguments, got {len(args)}."
+            )
+
+        for arg in args:
+            if not isinstance(arg, Expression):
+                raise TypeError(f"The {operation} arguments must be expressions, got {type(arg)}.")
+
     # Predicates
     @property
     def is_value(self) -> bool:
@@ -192,10 +456,29 @@ class Expression:
     def is_one(self) -> bool:
         return self.head == Expression.Tag.VALUE and self[0] == 1
 
+    @property
+    def is_nan(self) -> bool:
+        """Returns true if the expression is a numerical value with a `NaN` component."""
+        return self.head == Expression.Tag.VALUE and cmath.isnan(self[0])
+
+    @property
+    def is_finite(self) -> bool:
+        """Returns true if the expression is a numerical value with finite components only."""
+        return self.head == Expression.Tag.VALUE and cmath.isfinite(self[0])
+
+    @property
+    def is_undefined(self) -> bool:
+        return self.head == Expression.Tag.SYMBOL and bool(self.get("undefined", False))
+
     @property
     def is_symbol(self) -> bool:
         return self.head == Expression.Tag.SYMBOL
 
+    @property
+    def is_indexed(self) -> bool:
+        """Returns true if the expression is an element of an array symbol, e.g., `x[i]`."""
+        return self.head == Expression.Tag.SYMBOL and "index" in self.attrs
+
     @property
     def is_function(self) -> bool:
         return self.head == Expression.Tag.FN
@@ -212,6 +495,13 @@ class Expression:
     def is_multiplication(self) -> bool:
         return self.head == Expression.Tag.MUL
 
+    @property
+    def is_commutative(self) -> bool:
+        """Returns true if the order of the arguments is irrelevant, which is the case for
+        additions and multiplications not created with `noncommutative_multiplication`.
+        """
+        return self.is_addition or (self.is_multiplication and self.get("commutative", True))
+
     @property
     def is_kronecker_product(self) -> bool:
         return self.head == Expression.Tag.KRON
@@ -251,7 +541,7 @@ class Expression:
         # Collecting only non-null term's subspaces.
         subspaces = []
         for arg in self.args:
-            sp = arg.subspace
+            sp = arg.subspace if isinstance(arg, Expression) else None
             if sp:
                 subspaces.append(sp)
 
@@ -290,6 +580,298 @@ class Expression:
         # Return the maximum index among all the terms.
         return max(map(lambda arg: arg.max_index, self.args))  # type: ignore
 
+    @cached_property
+    def free_symbols(self) -> set[str]:
+        """Returns the names of the symbols in the expression. The names of functions and quantum
+        operators are not included, only the symbols used as their arguments, and neither are the
+        indices bound by summations.
+
+        Example:
+        ```
+        >>> (a * sin(b) + RX(c)(0)).free_symbols
+        {'a', 'b', 'c'}
+        ```
+        """
+
+        return set(self._symbol_occurrences())
+
+    @cached_property
+    def node_count(self) -> int:
+        """Returns the number of expression nodes in the tree, including the expression itself.
+
+        Example:
+        ```
+        >>> (2 * x + 1).node_count
+        5
+        ```
+        """
+
+        return 1 + sum(arg.node_count for arg in self.args if isinstance(arg, Expression))
+
+    @cached_property
+    def depth(self) -> int:
+        """Returns the number of levels of the tree. Symbols and values have depth one, and every
+        operation adds one level.
+
+        Example:
+        ```
+        >>> (2 * x + 1).depth
+        3
+        ```
+        """
+
+        # The tree is traversed with an explicit stack, so deep trees don't exhaust the Python
+        # stack. The depths already cached in the subexpressions are reused.
+        stack: list[tuple[Expression, bool]] = [(self, False)]
+        while stack:
+            node, visited = stack.pop()
+            if "depth" in node.__dict__:
+                continue
+
+            children = [arg for arg in node.args if isinstance(arg, Expression)]
+            if visited:
+                depths = (child.__dict__["depth"] for child in children)
+                node.__dict__["depth"] = 1 + max(depths, default=0)
+            else:
+                stack.append((node, True))
+                stack.extend((child, False) for child in children)
+
+        return self.__dict__["depth"]  # type: ignore
+
+    def complexity_score(self) -> float:
+        """Returns a heuristic measure of the complexity of the expression, useful to decide
+        whether it is worth simplifying or caching.
+
+        The score is `node_count + DEPTH_WEIGHT * depth` plus the weight of every operation in the
+        tree, given by `COMPLEXITY_WEIGHTS`. Powers and function calls weigh three, the other
+        operations one, and the depth weighs two. Making an expression larger, e.g., raising a
+        subexpression to a power, always increases the score. It is a pragmatic estimate, not a
+        formal measure.
+
+        Example:
+        ```
+        >>> x.complexity_score()
+        3.0
+        >>> (x**2).complexity_score()
+        10.0
+        ```
+        """
+
+        weights = sum(COMPLEXITY_WEIGHTS[head.name] for head, _ in self.operators())
+        return self.node_count + DEPTH_WEIGHT * self.depth + weights
+
+    def memory_usage(self) -> int:
+        """Returns a rough estimate, in bytes, of the memory used by the expression tree.
+
+        The estimate adds up the sizes of the expression nodes, their arguments and attributes
+        as reported by `sys.getsizeof`. Objects shared by several branches, like repeated
+        subexpressions, are only counted once. It helps to decide when a large expression, e.g.,
+        a generated Hamiltonian, is worth simplifying before further manipulation.
+
+        Example:
+        ```
+        >>> (2 * x).memory_usage() > x.memory_usage()
+        True
+        ```
+        """
+
+        visited: set[int] = set()
+
+        def size(obj: Any) -> int:
+            if id(obj) in visited:
+                return 0
+            visited.add(id(obj))
+
+            total = sys.getsizeof(obj)
+
+            if isinstance(obj, Expression):
+                total += size(obj.__dict__) + size(obj.args) + size(obj.attrs)
+            elif isinstance(obj, dict):
+                total += sum(size(key) + size(val) for key, val in obj.items())
+            elif isinstance(obj, (tuple, list, set, frozenset)):
+                total += sum(size(item) for item in obj)
+            elif isinstance(obj, Support):
+                total += size(obj.__dict__)
+
+            return total
+
+        return size(self)
+
+    def subexpressions(self, min_nodes: int = 1) -> list[Expression]:
+        """Returns the distinct subexpressions, the expression itself included. Each subexpression
+        is listed after its own subexpressions, in order of first appearance, so the list can be
+        used as a computation schedule. The names of functions and quantum operators are skipped.
+
+        Example:
+        ```
+        >>> Expression.mul(x + 1, x + 1).subexpressions()
+        [1.0, x, 1.0 + x, (1.0 + x) * (1.0 + x)]
+        >>> Expression.mul(x + 1, x + 1).subexpressions(min_nodes=2)
+        [1.0 + x, (1.0 + x) * (1.0 + x)]
+        ```
+
+        Args:
+            min_nodes: Ignore subexpressions with less than `min_nodes` nodes, e.g., `2` discards
+                the symbols and values.
+        """
+
+        visited: dict[Expression, None] = dict()
+
+        def visit(expr: Expression) -> None:
+            if expr in visited:
+                return
+
+            if expr.is_function:
+                args = expr[1:]
+            elif expr.is_quantum_operator:
+                args = () if expr[0].is_symbol else expr[:1]
+            else:
+                args = expr.args
+
+            for arg in args:
+                if isinstance(arg, Expression):
+                    visit(arg)
+
+            visited[expr] = None
+
+        visit(self)
+        return [expr for expr in visited if expr.node_count >= min_nodes]
+
+    def leaves(self) -> Iterator[Expression]:
+        """Yields the symbols and values of the expression in pre-order. As in `free_symbols`, the
+        names of functions and quantum operators are skipped.
+
+        Example:
+        ```
+        >>> list((x**2 + 1).leaves())
+        [1.0, x, 2.0]
+        ```
+        """
+
+        return (expr for expr in self._preorder() if expr.is_value or expr.is_symbol)
+
+    def operators(self) -> Iterator[tuple[Expression.Tag, int]]:
+        """Yields the tag and the number of arguments of every node other than symbols and values,
+        in pre-order. The names of functions and quantum operators are not counted as arguments.
+
+        Example:
+        ```
+        >>> list((x**2 + 1).operators())
+        [(<Tag.ADD: 'Add'>, 2), (<Tag.POW: 'Power'>, 2)]
+        ```
+        """
+
+        for expr in self._preorder():
+            if not (expr.is_value or expr.is_symbol):
+                yield expr.head, len(expr._children())
+
+    def walk_paths(self) -> Iterator[tuple[tuple[int, ...], Expression]]:
+        """Yields every node of the expression in pre-order, paired with its path.
+
+        The path is the sequence of argument indices leading from the expression to the node, so
+        the node at `(1, 0)` is `expr[1][0]`. The empty path refers to the expression itself.
+        Unlike `leaves`, the names of functions and quantum operators are included.
+
+        Example:
+        ```
+        >>> [path for path, _ in ((x + 1) * y).walk_paths()]
+        [(), (0,), (0, 0), (0, 1), (1,)]
+        ```
+        """
+
+        stack: list[tuple[tuple[int, ...], Expression]] = [((), self)]
+        while stack:
+            path, expr = stack.pop()
+            yield path, expr
+            stack.extend(
+                ((*path, i), arg)
+                for i, arg in reversed(list(enumerate(expr.args)))
+                if isinstance(arg, Expression)
+            )
+
+    def get_at(self, path: Iterable[int]) -> Expression | None:
+        """Returns the node at the `path` given by `walk_paths`, or `None` if there is no
+        expression at that location.
+
+        Example:
+        ```
+        >>> ((x + 1) * y).get_at((0, 1))
+        x
+        ```
+        """
+
+        expr = self
+        for i in path:
+            if not (0 <= i < len(expr.args) and isinstance(expr.args[i], Expression)):
+                return None
+            expr = expr.args[i]
+        return expr
+
+    def _preorder(self) -> Iterator[Expression]:
+        """Traverse the expression with an explicit stack, so deep trees don't hit the recursion
+        limit.
+        """
+
+        stack = [self]
+        while stack:
+            expr = stack.pop()
+            yield expr
+            stack.extend(reversed(expr._children()))
+
+    def _children(self) -> tuple[Expression, ...]:
+        if self.is_value or self.is_symbol:
+            return ()
+
+        if self.is_function:
+            args = self[1:]
+        elif self.is_quantum_operator:
+            args = () if self[0].is_symbol else self[:1]
+        else:
+            args = self.args
+
+        return tuple(arg for arg in args if isinstance(arg, Expression))
+
+    def free_symbols_with_counts(self) -> dict[str, int]:
+        """Returns the number of occurrences of each symbol in the expression. As in
+        `free_symbols`, the names of functions and quantum operators are not counted.
+
+        Example:
+        ```
+        >>> (x * sin(x) + x * y).free_symbols_with_counts()
+        {'x': 3, 'y': 1}
+        ```
+        """
+
+        return dict(Counter(self._symbol_occurrences()))
+
+    def _symbol_occurrences(self) -> Iterator[str]:
+        """Yields the name of every symbol occurrence, skipping function and operator names."""
+
+        # The undefined value is represented by a marked symbol, but it is not a free symbol.
+        if self.is_value or self.is_undefined:
+            return
+
+        if self.is_symbol:
+            yield self[0]
+            index = self.get("index")
+            if isinstance(index, Expression):
+                yield from index._symbol_occurrences()
+            return
+
+        if self.is_function:
+            args = self[1:]
+        elif self.is_quantum_operator:
+            args = self[0][1:] if self[0].is_function else () if self[0].is_symbol else self[:1]
+        else:
+            args = self.args
+
+        # The index of a summation is bound by it, e.g., `i` in `sum(x[i], i, 0, n)`.
+        bound = self.get("bound_index")
+
+        for arg in args:
+            if isinstance(arg, Expression):
+                yield from (name for name in arg._symbol_occurrences() if name != bound)
+
     # Helper functions.
     def get(self, attribute: str, default: Any | None = None) -> Any:
         """Retrieve the value of the chosen `attribute` if it exists, or return the `default` value
@@ -344,14 +926,145 @@ class Expression:
         args = tuple(arg.dag for arg in self.args)
         return Expression(self.head, *args, **self.attrs)
 
+    def coefficient(self) -> Numeric:
+        """Returns the leading numerical factor of a product, the number itself for a value, or
+        one otherwise.
+
+        Example:
+        ```
+        >>> (3 * x * y).coefficient()
+        3.0
+        >>> (x + y).coefficient()
+        1.0
+        ```
+        """
+
+        if self.is_value:
+            return self[0]  # type: ignore [no-any-return]
+
+        if self.is_multiplication and self[0].is_value:
+            return self[0][0]  # type: ignore [no-any-return]
+
+        return 1.0
+
+    def without_coefficient(self) -> Expression:
+        """Returns the expression without the factor given by `coefficient`, or one for values.
+
+        Example:
+        ```
+        >>> (3 * x * y).without_coefficient()
+        x * y
+        ```
+        """
+
+        if self.is_value:
+            return Expression.one()
+
+        if self.is_multiplication and self[0].is_value:
+            if len(self.args) == 2:
+                return self[1]  # type: ignore [no-any-return]
+            return Expression(self.head, *self[1:], **self.attrs)
+
+        return self
+
+    def with_coefficient(self, coefficient: Numeric) -> Expression:
+        """Returns the expression with the factor given by `coefficient` replaced by a new one.
+
+        The result is built without distributing the coefficient, so the `coefficient` of the
+        result is the new one, e.g., for sums.
+
+        Example:
+        ```
+        >>> (3 * x * y).with_coefficient(-2)
+        -2.0 * x * y
+        >>> (x + y).with_coefficient(2)
+        2.0 * (x + y)
+        ```
+        """
+
+        term = self.without_coefficient()
+
+        if coefficient == 0 or term.is_one:
+            return Expression.value(coefficient)
+
+        if coefficient == 1:
+            return term
+
+        if term.is_multiplication:
+            return Expression(term.head, Expression.value(coefficient), *term.args, **term.attrs)
+
+        return Expression.mul(Expression.value(coefficient), term)
+
+    def sort_key(self) -> tuple[Any, ...]:
+        """Returns a key defining a total order among expressions, suitable to sort collections of
+        expressions in a canonical way.
+
+        The expressions are ordered first by their tag, following the order of the `Tag`
+        definition, so values come before symbols and symbols before functions, quantum operators,
+        and compound expressions. Expressions with the same tag are ordered by their numerical
+        value, name, or arguments. Numerical values are ordered by `numeric_sort_key`.
+
+        Example:
+        ```
+        >>> sorted([a * b, X(0), 2, a], key=Expression.sort_key)
+        [2.0, a, X[0], a * b]
+        ```
+        """
+
+        rank = list(Expression.Tag).index(self.head)
+
+        if self.is_value:
+            return (rank, numeric_sort_key(self[0]))
+
+        if self.is_symbol:
+            return (rank, (self[0], str(self.get("index", ""))))
+
+        if self.is_quantum_operator:
+            support: Support = self[1]
+            dagger = bool(self.get("is_dagger", False))
+            return (rank, (self[0].sort_key(), support.target, support.control, dagger))
+
+        # Functions can hold raw numerical arguments, e.g., the angle of a parametric operator.
+        keys = tuple(
+            arg.sort_key() if isinstance(arg, Expression) else (-1, numeric_sort_key(arg))
+            for arg in self.args
+        )
+
+        # The order of the terms in additions and multiplications is irrelevant.
+        if self.is_commutative:
+            keys = tuple(sorted(keys))
+
+        return (rank, keys)
+
     def __getitem__(self, index: int | slice) -> Any:
         """Makes the arguments of the expression directly accessible through `expression[i]`."""
         return self.args[index]
 
     def __hash__(self) -> int:
-        if self.is_addition or self.is_multiplication:
+        if self._hash is None:
+            self._hash = self._compute_hash()
+        return self._hash
+
+    def __getstate__(self) -> dict[str, Any]:
+        # The hashes of strings change between processes, so the cached hash is not pickled.
+        return {**self.__dict__, "_hash": None}
+
+    def _compute_hash(self) -> int:
+        # `NaN` values are hashed alike to keep the hash consistent with `total_eq`.
+        if self.is_nan:
+            return hash((self.head, "nan"))
+
+        # Values are hashed as the numbers they hold to keep the hash consistent with `==`.
+        if self.is_value:
+            return hash(self[0])
+
+        if self.is_commutative:
             return hash((self.head, frozenset(self.args)))
 
+        # Elements of the same array are distinguished by their index.
+        if self.is_symbol and "index" in self.attrs:
+            return hash((self.head, self.args, self.attrs["index"]))
+
         return hash((self.head, self.args))
 
     def __repr__(self) -> str:
@@ -363,20 +1076,96 @@ class Expression:
     def __str__(self) -> str:
         return visualize_expression(self)
 
+    def to_string(self, options: FormatOptions | None = None) -> str:
+        """Stringify the expression with customised format `options`.
+
+        Example:
+        ```
+        >>> value(2.3e10).to_string(FormatOptions(scientific=True))
+        '2.3e+10'
+        ```
+        """
+
+        return visualize_expression(self, options or FormatOptions())
+
+    def pretty(self, width: int = 80, options: FormatOptions | None = None) -> str:
+        """Stringify the expression over multiple lines when it doesn't fit in `width` characters.
+
+        Long sums are written with one term per line, with the signs aligned in the first column.
+        Long products and powers are split at their bracketed factors, whose content is indented.
+        Expressions fitting in `width` are written in a single line, as `str`.
+
+        Example:
+        ```
+        >>> expr = Expression.mul(a + b, c + d + e)
+        >>> print(expr)
+        (a + b) * (c + d + e)
+        >>> print(expr.pretty(width=10))
+        (a + b) * (
+            c
+          + d
+          + e
+        )
+        ```
+        """
+
+        return visualize_pretty(self, width, 0, options or FormatOptions())
+
     def _repr_pretty_(self, p, _cycle) -> None:  # type: ignore
         """IPython method: Provide a friendly visualisation when using IPython/Jupyter notebook."""
 
         p.text(str(self))
 
     def __eq__(self, other: object) -> bool:
+        # Numbers are equal to the value expressions holding them, e.g., `value(3) == 3`.
+        if isinstance(other, (int, float, complex)) and not isinstance(other, bool):
+            return self.is_value and self[0] == other
+
         if not isinstance(other, Expression):
             return NotImplemented
 
-        lhs_args = set(self.args) if self.is_addition or self.is_multiplication else self.args
-        rhs_args = set(other.args) if other.is_addition or other.is_multiplication else other.args
+        # Expressions with different hashes are different, avoiding the structural comparison.
+        if hash(self) != hash(other):
+            return False
+
+        # The arguments of commutative operations are compared as multisets.
+        lhs_args = Counter(self.args) if self.is_commutative else self.args
+        rhs_args = Counter(other.args) if other.is_commutative else other.args
 
         return self.head == other.head and lhs_args == rhs_args and self.attrs == other.attrs
 
+    def total_eq(self, other: Expression) -> bool:
+        """Structural equality where `NaN` values are considered equal to each other.
+
+        The `==` operator follows the IEEE 754 semantics, so `value(nan) != value(nan)`. This
+        method provides a total equality useful to deduplicate expressions that may hold `NaN`
+        values, e.g., after replacing symbols by ill-defined numerical values.
+        """
+
+        if self.is_nan and other.is_nan:
+            return True
+
+        if self.head != other.head or self.attrs != other.attrs:
+            return False
+
+        if len(self.args) != len(other.args):
+            return False
+
+        # The terms of additions and multiplications are compared regardless of their order.
+        if self.is_commutative:
+            remaining = list(other.args)
+            for arg in self.args:
+                match = next((i for i, x in enumerate(remaining) if arg.total_eq(x)), None)
+                if match is None:
+                    return False
+                remaining.pop(match)
+            return True
+
+        return all(
+            lhs.total_eq(rhs) if isinstance(lhs, Expression) else lhs == rhs
+            for lhs, rhs in zip(self.args, other.args)
+        )
+
     # Algebraic operations
     def __add__(self, other: object) -> Expression:
         if not isinstance(other, Expression | Numeric):
@@ -386,16 +1175,21 @@ class Expression:
         if isinstance(other, Numeric):
             return self + Expression.value(other)
 
+        if self.is_undefined or other.is_undefined:
+            return Expression.undefined()
+
         # Addition identity: a + 0 = 0 + a = a
-        if self.is_zero:
+        identity = Expression.Tag.ADD.identity()
+        if self == identity:
             return other
 
-        if other.is_zero:
+        if other == identity:
             return self
 
-        # Numerical values are added right away
+        # Numerical values are added right away, with indeterminate sums like `inf - inf`
+        # being undefined.
         if self.is_value and other.is_value:
-            return Expression.value(self[0] + other[0])
+            return _value_or_undefined(self[0] + other[0], self, other)
 
         if self.is_addition and other.is_addition:
             args = (*self.args, *other.args)
@@ -427,19 +1221,31 @@ class Expression:
         if isinstance(other, Numeric):
             return self * Expression.value(other)
 
-        # Null multiplication shortcut.
-        if self.is_zero or other.is_zero:
-            return Expression.zero()
+        if self.is_undefined or other.is_undefined:
+            return Expression.undefined()
+
+        # Null multiplication shortcut, except for the indeterminate `0 * inf`.
+        absorbing = Expression.Tag.MUL.absorbing()
+        if self == absorbing or other == absorbing:
+            if _is_infinite(self) or _is_infinite(other):
+                return Expression.undefined()
+            return absorbing  # type: ignore
 
         # Identity multiplication shortcut.
-        if self.is_one:
+        identity = Expression.Tag.MUL.identity()
+        if self == identity:
             return other
-        if other.is_one:
+        if other == identity:
             return self
 
-        # Numerical values are multiplied right away.
+        # Numerical values are multiplied right away, with products of powers of the imaginary
+        # unit reduced to one of `1, i, -1, -i`.
         if self.is_value and other.is_value:
-            return Expression.value(self[0] * other[0])
+            k = imaginary_unit_exponent(self[0])
+            n = imaginary_unit_exponent(other[0])
+            if k is not None and n is not None:
+                return Expression.value(IMAGINARY_UNIT_POWERS[(k + n) % 4])
+            return _value_or_undefined(self[0] * other[0], self, other)
 
         # Distributive rule
         if self.is_addition and not (other.is_power and self == other[0]):
@@ -457,6 +1263,14 @@ class Expression:
         else:
             args = (self, other)
 
+        # Products created with `noncommutative_multiplication` keep the order of their factors
+        # when they are extended outside of the context.
+        noncommutative = any(
+            arg.is_multiplication and not arg.is_commutative for arg in (self, other)
+        )
+        if noncommutative or not COMMUTATIVE_MULTIPLICATION.get():
+            return evaluate_noncommutative_multiplication(Expression.mul(*args))
+
         # ⚠️ Warning: Ideally, this step should not perform the evaluation of the
         # expression. However, we want to provide a friendly interaction to the users,
         # and the inaccessibility of Python's evaluation (without writing our own REPL)
@@ -471,7 +1285,12 @@ class Expression:
         return NotImplemented
 
     def __pow__(self, other: object) -> Expression:
-        """Power involving quantum operators always promote expression to quantum operators."""
+        """Power involving quantum operators always promote expression to quantum operators.
+
+        Powers of numerical values are computed with Python's power, so zero to a negative power
+        raises `ZeroDivisionError`, large results raise `OverflowError` or become infinite, and
+        non-finite values propagate. Use `checked_pow` to get a `ValueError` in all these cases.
+        """
 
         if not isinstance(other, Expression | Numeric):
             return NotImplemented
@@ -479,9 +1298,20 @@ class Expression:
         if isinstance(other, Numeric):
             return self ** Expression.value(other)
 
-        # Numerical values are computed right away.
+        if self.is_undefined or other.is_undefined:
+            return Expression.undefined()
+
+        # Numerical values are computed right away. Integer powers of the imaginary unit are
+        # reduced exactly to one of `1, i, -1, -i`.
         if self.is_value and other.is_value:
-            return Expression.value(self[0] ** other[0])
+            k = imaginary_unit_exponent(self[0])
+            if (
+                k is not None
+                and not isinstance(other[0], complex)
+                and float(other[0]).is_integer()
+            ):
+                return Expression.value(IMAGINARY_UNIT_POWERS[k * int(other[0]) % 4])
+            return Expression.value(numeric_pow(self[0], other[0]))
 
         # Null power shortcut.
         if other.is_zero:
@@ -503,6 +1333,19 @@ class Expression:
             power = int(other[0]) % 2
             return self if power == 1 else Expression.one()
 
+        # Integer powers of commutative products without quantum operators are distributed over
+        # the factors, `(a * b)^n = a^n * b^n`, allowing reciprocal factors to cancel out in
+        # multiplications.
+        if (
+            self.is_multiplication
+            and self.is_commutative
+            and self.subspace is None
+            and other.is_value
+            and not isinstance(other[0], complex)
+            and float(other[0]).is_integer()
+        ):
+            return reduce(lambda acc, x: acc * x**other, self.args, Expression.one())
+
         # Power of power is an simple operation and can be evaluated here.
         # Whenever a quantum operator is present, the expression is promoted to
         # a quantum operator.
@@ -521,6 +1364,25 @@ class Expression:
     def __neg__(self) -> Expression:
         return -1 * self
 
+    def __abs__(self) -> Expression:
+        """The absolute value of numerical values is evaluated, otherwise it is kept unevaluated
+        as the function `abs`, displayed as `|x|`.
+        """
+
+        if self.is_value:
+            return Expression.value(abs(self[0]))
+
+        if self.is_undefined:
+            return self
+
+        if self.subspace is not None:
+            raise TypeError("The absolute value is not defined for quantum operators.")
+
+        if self.is_function and self[0][0] == "abs":
+            return self
+
+        return Expression.function("abs", self)
+
     def __sub__(self, other: object) -> Expression:
         if not isinstance(other, Expression | Numeric):
             return NotImplemented
@@ -537,6 +1399,14 @@ class Expression:
         if not isinstance(other, Expression | Numeric):
             return NotImplemented
 
+        if isinstance(other, Numeric):
+            other = Expression.value(other)
+
+        # The indeterminate quotients `0 / 0` and `inf / inf` are undefined.
+        if self.is_value and other.is_value:
+            if (self.is_zero and other.is_zero) or (_is_infinite(self) and _is_infinite(other)):
+                return Expression.undefined()
+
         return self * (other**-1)
 
     def __rtruediv__(self, other: object) -> Expression:
@@ -545,6 +1415,53 @@ class Expression:
 
         return other * (self**-1)  # type: ignore
 
+    def __floordiv__(self, other: object) -> Expression:
+        """The floor division, `x // y`. It is evaluated for numerical values, otherwise it is
+        kept unevaluated as the function `floordiv`, displayed as `x // y`.
+        """
+        return self._integer_division("floordiv", other)
+
+    def __rfloordiv__(self, other: object) -> Expression:
+        if not isinstance(other, Numeric):
+            return NotImplemented
+
+        return Expression.value(other) // self
+
+    def __mod__(self, other: object) -> Expression:
+        """The remainder of the floor division, `x % y`, with the sign of `y`, as for Python's
+        numbers. It is evaluated for numerical values, otherwise it is kept unevaluated as the
+        function `mod`, displayed as `x % y`.
+        """
+        return self._integer_division("mod", other)
+
+    def __rmod__(self, other: object) -> Expression:
+        if not isinstance(other, Numeric):
+            return NotImplemented
+
+        return Expression.value(other) % self
+
+    def _integer_division(self, name: str, other: object) -> Expression:
+        if not isinstance(other, Expression | Numeric):
+            return NotImplemented
+
+        if isinstance(other, Numeric):
+            other = Expression.value(other)
+
+        if self.is_undefined or other.is_undefined:
+            return Expression.undefined()
+
+        fn, symbol = INTEGER_DIVISIONS[name]
+
+        if self.subspace is not None or other.subspace is not None:
+            raise TypeError(f"The operator '{symbol}' is not defined for quantum operators.")
+
+        if self.is_value and other.is_value:
+            if isinstance(self[0], complex) or isinstance(other[0], complex):
+                raise TypeError(f"The operator '{symbol}' is not defined for complex numbers.")
+            return Expression.value(fn(self[0], other[0]))
+
+        return Expression.function(name, self, other)
+
     def __kron__(self, other: object) -> Expression:
         if not isinstance(other, Expression):
             return NotImplemented
@@ -560,15 +1477,20 @@ class Expression:
         ):
             raise SyntaxError(f"__kron__ cannot be used with {self} and {other}")
 
+        if self.is_undefined or other.is_undefined:
+            return Expression.undefined()
+
         # Null multiplication shortcut.
-        if self.is_zero or other.is_zero:
-            return Expression.zero()
+        absorbing = Expression.Tag.KRON.absorbing()
+        if self == absorbing or other == absorbing:
+            return absorbing  # type: ignore
 
         # Identity multiplication shortcut.
-        if self.is_one:
+        identity = Expression.Tag.KRON.identity()
+        if self == identity:
             return other
 
-        if other.is_one:
+        if other == identity:
             return self
 
         # ⚠️ Warning: Ideally, this step should not perform the evaluation of the
@@ -586,6 +1508,17 @@ class Expression:
         return self.__kron__(other)
 
 
+def _is_infinite(expr: Expression) -> bool:
+    return expr.is_value and not (expr.is_finite or expr.is_nan)
+
+
+def _value_or_undefined(x: Numeric, *operands: Expression) -> Expression:
+    # A `NaN` result from operands without `NaN`, e.g., `inf - inf` or `0 * inf`, is undefined.
+    if cmath.isnan(x) and not any(operand.is_nan for operand in operands):
+        return Expression.undefined()
+    return Expression.value(x)
+
+
 def evaluate_addition(expr: Expression) -> Expression:
     if not expr.is_addition:
         return expr
@@ -604,17 +1537,26 @@ def evaluate_addition(expr: Expression) -> Expression:
         elif term.is_multiplication and term[0].is_value:
             # Isolate the numerical coefficient from the other symbols.
             coef = term[0]
-            elem = term[1] if len(term.args) == 2 else Expression.mul(*term[1:])
+            elem = (
+                term[1] if len(term.args) == 2 else Expression(term.head, *term[1:], **term.attrs)
+            )
 
             general_terms[elem] = general_terms.get(elem, Expression.zero()) + coef
 
         else:
             general_terms[term] = general_terms.get(term, Expression.zero()) + Expression.one()
 
-    # The final terms are recombined multipling each one by their respective coefficients.
-    args = tuple(elem * coef for elem, coef in general_terms.items())
-
-    if not numerical_value_accumulator.is_zero:
+    # The final terms are recombined multipling each one by their respective coefficients. Terms
+    # cancelled out, like in `(a + b) - (a + b)`, are dropped. The terms are sorted by their
+    # non-numerical part, so the result doesn't depend on the order of construction, and the
+    # numerical value always comes first.
+    args = tuple(
+        elem * coef
+        for elem, coef in sorted(general_terms.items(), key=lambda item: item[0].sort_key())
+        if not coef.is_zero
+    )
+
+    if not numerical_value_accumulator.is_zero or not args:
         args = (numerical_value_accumulator, *args)
 
     return args[0] if len(args) == 1 else Expression.add(*args)
@@ -664,6 +1606,61 @@ def evaluate_multiplication(expr: Expression) -> Expression:
     return args[0] if len(args) == 1 else Expression.mul(*args)
 
 
+def evaluate_noncommutative_multiplication(expr: Expression) -> Expression:
+    """Evaluate multiplications preserving the order of the factors.
+
+    Only the numerical values are combined and moved to the front of the product.
+    """
+
+    if not expr.is_multiplication:
+        return expr
+
+    numerical_value_accumulator = Expression.one()
+    factors = []
+
+    for term in expr.args:
+        if term.is_value:
+            numerical_value_accumulator = numerical_value_accumulator * term
+        else:
+            factors.append(term)
+
+    if numerical_value_accumulator.is_zero:
+        return Expression.zero()
+
+    if not numerical_value_accumulator.is_one or len(factors) == 0:
+        factors = [numerical_value_accumulator, *factors]
+
+    if len(factors) == 1:
+        return factors[0]  # type: ignore
+
+    return Expression(Expression.Tag.MUL, *factors, commutative=False)
+
+
+@contextmanager
+def noncommutative_multiplication() -> Iterator[None]:
+    """Disable the commutative rules of the multiplication within the context.
+
+    Inside the context, multiplications preserve the order of their factors, combining only
+    the numerical values, as needed in pure operator algebras. The setting is bound to the
+    current execution context, so it does not leak to other threads or asynchronous tasks.
+
+    Example:
+    ```
+    >>> with noncommutative_multiplication():
+    ...     A * B - B * A
+    A * B - B * A
+    >>> A * B - B * A
+    0.0
+    ```
+    """
+
+    token = COMMUTATIVE_MULTIPLICATION.set(False)
+    try:
+        yield
+    finally:
+        COMMUTATIVE_MULTIPLICATION.reset(token)
+
+
 def evaluate_kron(expr: Expression) -> Expression:
     """Evaluate Kronecker product expressions."""
 
@@ -815,15 +1812,14 @@ def evaluate_kronop(lhs: Expression, rhs: Expression) -> Expression:
                 else Expression.quantum_operator(res, lhs[1], **lhs.attrs)
             )
 
-        # Simplify the multiplication of unitary Hermitian operators with fractional
-        # power, e.g., `√X() * √X() == X()`.
-        if (
-            lhs[0].is_power
-            and rhs[0].is_power
-            and lhs[0][0] == rhs[0][0]  # both are the same operator
-            and (lhs[0][0].get("is_hermitian") and lhs[0][0].get("is_unitary"))
-        ):
-            return lhs[0][0] ** (lhs[0][1] + rhs[0][1])  # type: ignore
+        # Merge the powers of the same operator, e.g., `√X() * √X() == X()` or
+        # `A() * A()^-1 == 1`. Plain products like `A() * A()` are kept as they are.
+        if lhs[0].is_power or rhs[0].is_power:
+            lhs_base, lhs_power = lhs[0][:2] if lhs[0].is_power else (lhs, Expression.one())
+            rhs_base, rhs_power = rhs[0][:2] if rhs[0].is_power else (rhs, Expression.one())
+
+            if lhs_base == rhs_base:
+                return lhs_base ** (lhs_power + rhs_power)  # type: ignore
 
     # Order the operators by subspace.
     if lhs.subspace < rhs.subspace or lhs.subspace.overlap_with(  # type: ignore
@@ -834,40 +1830,200 @@ def evaluate_kronop(lhs: Expression, rhs: Expression) -> Expression:
     return Expression.kron(rhs, lhs)
 
 
-def visualize_expression(expr: Expression) -> str:
+@dataclass(frozen=True)
+class FormatOptions:
+    """Options to customise the visualisation of expressions.
+
+    Args:
+        scientific: Render floating-point values in scientific notation. Complex values have the
+            notation applied to both components.
+        precision: Number of digits after the decimal point in scientific notation. By default,
+            the shortest representation that preserves the value is used.
+        exponent_style: Use `"e"` (`1.5e-9`), `"E"` (`1.5E-9`), or `"x10"` (`1.5×10^-9`) to
+            represent the exponent in scientific notation. Only the first two can be parsed back.
+        mul_style: Use `"asterisk"` (`2 * x`), `"dot"` (`2 · x`), or `"implicit"` (`2x`) to
+            represent multiplications. The implicit style only omits the operator between a real
+            coefficient and a symbol, keeping the asterisk elsewhere to avoid ambiguities.
+    """
+
+    scientific: bool = False
+    precision: int | None = None
+    exponent_style: Literal["e", "E", "x10"] = "e"
+    mul_style: Literal["asterisk", "dot", "implicit"] = "asterisk"
+
+
+def visualize_expression(expr: Expression, options: FormatOptions = FormatOptions()) -> str:
     """Stringfy expressions."""
 
-    if expr.is_value or expr.is_symbol:
-        return str(expr[0])
+    if expr.is_value:
+        return visualize_number(expr[0], options)
+
+    if expr.is_symbol:
+        index = expr.get("index")
+        return str(expr[0]) if index is None else f"{expr[0]}[{index}]"
 
     if expr.is_quantum_operator:
         dag = "\u2020" if expr.get("is_dagger") else ""
         if expr[0].is_symbol or expr[0].is_function:
-            return f"{expr[0]}{dag}{expr[1]}"
-        return f"{expr[0]}"
+            return f"{visualize_expression(expr[0], options)}{dag}{expr[1]}"
+        return visualize_expression(expr[0], options)
+
+    if expr.is_function and expr[0][0] == "abs" and len(expr.args) == 2:
+        return f"|{visualize_expression(expr[1], options)}|"
+
+    if expr.is_function and expr[0][0] in INTEGER_DIVISIONS and len(expr.args) == 3:
+        symbol = INTEGER_DIVISIONS[expr[0][0]][1]
+        return f" {symbol} ".join(visualize_with_brackets(arg, options) for arg in expr[1:])
 
     if expr.is_function:
-        args = ",\u2009".join(map(str, expr[1:]))
-        return f"{expr[0]}({args})"
+        args = [
+            visualize_expression(arg, options) if isinstance(arg, Expression) else str(arg)
+            for arg in expr[1:]
+        ]
+        keywords = expr.get("keywords", ())
+        if keywords:
+            positional = len(args) - len(keywords)
+            args[positional:] = [f"{k}={v}" for k, v in zip(keywords, args[positional:])]
+        return f"{expr[0]}(" + ",\u2009".join(args) + ")"
 
     if expr.is_multiplication:
-        result = visualize_sequence(expr, "\u2009*\u2009")
-        return sub(r"-1\.0(\s\*)?\s", "-", result)
+        factors = expr.args
+        sign = ""
+        # Only the leading coefficient is a sign, `-1.0` elsewhere can be, e.g., an exponent.
+        if len(factors) > 1 and factors[0].is_value and visualize_number(factors[0][0]) == "-1.0":
+            sign, factors = "-", factors[1:]
+        return sign + visualize_factors(factors, options)
 
     if expr.is_kronecker_product:
-        return visualize_sequence(expr, "\u2009*\u2009")
+        return visualize_sequence(expr, MUL_OPERATORS[options.mul_style], options=options)
 
     if expr.is_addition:
-        result = visualize_sequence(expr, " + ", with_brackets=False)
-        return sub(r"\s\+\s-(1\.0(\s\*)?\s)?", " - ", result)
+        # Terms with a negative leading coefficient are shown as subtractions, e.g., `x - y`
+        # instead of `x + -y`. The sign of the first term is kept as it is.
+        first, *rest = (visualize_expression(arg, options) for arg in expr.args)
+        return first + "".join(
+            f" - {term[1:]}" if term.startswith("-") else f" + {term}" for term in rest
+        )
 
     if expr.is_power:
-        return visualize_sequence(expr, "\u2009^\u2009")
+        return visualize_sequence(expr, "\u2009^\u2009", options=options)
 
     return repr(expr)
 
 
-def visualize_sequence(expr: Expression, operator: str, with_brackets: bool = True) -> str:
+def visualize_pretty(
+    expr: Expression, width: int, indent: int, options: FormatOptions = FormatOptions()
+) -> str:
+    """Stringfy expressions over multiple lines, starting at the column `indent`."""
+
+    line = visualize_expression(expr, options)
+    if indent + len(line) <= width:
+        return line
+
+    if expr.is_addition:
+        lines = []
+        for i, arg in enumerate(expr.args):
+            term = visualize_pretty(arg, width, indent + 2, options)
+            sign = "-" if term.startswith("-") else "+" if i > 0 else " "
+            lines.append(f"{sign} {term[1:] if sign == '-' else term}")
+        return ("\n" + " " * indent).join(lines)
+
+    if expr.is_multiplication or expr.is_kronecker_product or expr.is_power:
+        factors = expr.args
+        sign = ""
+        if expr.is_multiplication and factors[0].is_value and factors[0] == -1 and len(factors) > 1:
+            sign, factors = "-", factors[1:]
+
+        operator = "\u2009^\u2009" if expr.is_power else MUL_OPERATORS[options.mul_style]
+        return sign + operator.join(
+            visualize_pretty_with_brackets(factor, width, indent, options) for factor in factors
+        )
+
+    return line
+
+
+def visualize_pretty_with_brackets(
+    expr: Expression, width: int, indent: int, options: FormatOptions = FormatOptions()
+) -> str:
+    """Stringfy additions and multiplications surrounded by brackets over multiple lines."""
+
+    if not (expr.is_multiplication or expr.is_addition):
+        return visualize_pretty(expr, width, indent, options)
+
+    content = visualize_pretty(expr, width, indent + 2, options)
+    if "\n" not in content:
+        return f"({content})"
+
+    return "(\n" + " " * (indent + 2) + content + "\n" + " " * indent + ")"
+
+
+# Multiplication operator of each `FormatOptions.mul_style`. The implicit style falls back to the
+# asterisk where the operator can't be omitted.
+MUL_OPERATORS = {
+    "asterisk": "\u2009*\u2009",
+    "dot": "\u2009\u00b7\u2009",
+    "implicit": "\u2009*\u2009",
+}
+
+
+def visualize_factors(
+    factors: tuple[Expression, ...], options: FormatOptions = FormatOptions()
+) -> str:
+    """Stringfy the factors of a multiplication according to the `mul_style` option."""
+
+    operator = MUL_OPERATORS[options.mul_style]
+    result = visualize_with_brackets(factors[0], options)
+
+    for previous, factor in zip(factors, factors[1:]):
+        # The operator is kept when the juxtaposition reads as a single number, e.g., `2.0e5`
+        # or `3.0j`, or the coefficient is written in exponent form.
+        implicit = (
+            options.mul_style == "implicit"
+            and previous.is_value
+            and not isinstance(previous[0], complex)
+            and factor.is_symbol
+            and not factor[0].startswith(("e", "E", "j", "J"))
+            and result.lstrip("-").replace(".", "", 1).isdigit()
+        )
+        result += ("" if implicit else operator) + visualize_with_brackets(factor, options)
+
+    return result
+
+
+def visualize_number(x: Numeric, options: FormatOptions = FormatOptions()) -> str:
+    """Stringfy numerical values according to the format `options`."""
+
+    if not options.scientific or isinstance(x, int):
+        return str(x)
+
+    if isinstance(x, complex):
+        real = visualize_number(x.real, options)
+        imag = visualize_number(abs(x.imag), options)
+        sign = "-" if x.imag < 0 else "+"
+        return f"({real}{sign}{imag}j)"
+
+    if not cmath.isfinite(x):
+        return str(x)
+
+    number = Decimal(repr(x)).normalize()
+    result = format(number, "e" if options.precision is None else f".{options.precision}e")
+
+    if options.exponent_style == "E":
+        return result.upper()
+
+    if options.exponent_style == "x10":
+        mantissa, exponent = result.split("e")
+        return f"{mantissa}\u00d710^{int(exponent)}"
+
+    return result
+
+
+def visualize_sequence(
+    expr: Expression,
+    operator: str,
+    with_brackets: bool = True,
+    options: FormatOptions = FormatOptions(),
+) -> str:
     """Stringfy the arguments of an expression `expr` with the designed `operator`.
 
     The `with_brackets` option wrap any argument that is either a multiplication or a sum.
@@ -877,15 +2033,21 @@ def visualize_sequence(expr: Expression, operator: str, with_brackets: bool = Tr
         raise SyntaxError("Only a sequence of expressions is allowed.")
 
     if with_brackets:
-        return operator.join(map(visualize_with_brackets, expr.args))
+        return operator.join(visualize_with_brackets(arg, options) for arg in expr.args)
 
-    return operator.join(map(str, expr.args))
+    return operator.join(visualize_expression(arg, options) for arg in expr.args)
 
 
-def visualize_with_brackets(expr: Expression) -> str:
-    """Stringfy addition and multiplication expression surrounded by brackets."""
+def visualize_with_brackets(expr: Expression, options: FormatOptions = FormatOptions()) -> str:
+    """Stringfy addition, multiplication, and integer division expressions surrounded by
+    brackets.
+    """
 
-    if expr.is_multiplication or expr.is_addition:
-        return f"({str(expr)})"
+    if (
+        expr.is_multiplication
+        or expr.is_addition
+        or (expr.is_function and expr[0][0] in INTEGER_DIVISIONS and len(expr.args) == 3)
+    ):
+        return f"({visualize_expression(expr, options)})"
 
-    return str(expr)
+    return visualize_expression(expr, options)
@@ -1,6 +1,137 @@
 from __future__ import annotations
 
-from typing import Union
+import cmath
+import math
+from typing import TYPE_CHECKING, Union
+
+if TYPE_CHECKING:
+    from .expression import Expression
 
 
 Numeric = Union[complex | float | int]
+
+
+def numeric_sort_key(x: Numeric) -> tuple[tuple[bool, float], tuple[bool, float]]:
+    """Returns a key defining a total order among numerical values, used to sort them in a
+    deterministic way.
+
+    The values are ordered by their real part and then by their imaginary part, with `NaN`
+    components placed after all the others. This order is only meant for canonical sorting, e.g.,
+    of the terms of an expression, and has no mathematical meaning: complex numbers remain
+    unordered for the `<` and `>` operators.
+
+    Example:
+    ```
+    >>> sorted([1j, 2, -1 + 5j, 1 - 1j], key=numeric_sort_key)
+    [(-1+5j), 1j, (1-1j), 2]
+    ```
+    """
+
+    def component(y: float) -> tuple[bool, float]:
+        return (True, 0.0) if math.isnan(y) else (False, y)
+
+    number = complex(x)
+    return component(number.real), component(number.imag)
+
+
+# Powers of the imaginary unit, `i^k` for `k = 0, 1, 2, 3`.
+IMAGINARY_UNIT_POWERS: tuple[Numeric, ...] = (1.0, 1j, -1.0, complex(0, -1))
+
+
+def imaginary_unit_exponent(x: Numeric) -> int | None:
+    """Returns `k` such that the complex value `x` is exactly `i^k`, with `0 <= k < 4`, or `None`
+    if `x` is not complex or not a power of the imaginary unit.
+
+    Combined with `IMAGINARY_UNIT_POWERS`, it reduces products and integer powers of `i` with
+    modulo-4 arithmetic, avoiding the rounding errors of the complex power, e.g., `i^101`.
+    """
+
+    if not isinstance(x, complex):
+        return None
+
+    for k, power in enumerate(IMAGINARY_UNIT_POWERS):
+        if x == power:
+            return k
+
+    return None
+
+
+def numeric_pow(base: Numeric, exponent: Numeric) -> Numeric:
+    """Returns `base ^ exponent` following Python's power, except for the square roots of negative
+    real numbers, which are computed exactly as imaginary numbers.
+
+    Python promotes the fractional powers of negative real numbers to the complex principal
+    value, which leaves a rounding error in the real part, e.g., `(-4) ** 0.5` is
+    `1.2e-16 + 2j`.
+
+    Example:
+    ```
+    >>> numeric_pow(-4, 0.5)
+    2j
+    ```
+    """
+
+    if exponent == 0.5 and not isinstance(base, complex) and base < 0:
+        return complex(0, math.sqrt(-base))
+
+    return base**exponent  # type: ignore [no-any-return]
+
+
+def checked_pow(base: Numeric, exponent: Numeric) -> Numeric:
+    """Returns `base ^ exponent`, guaranteeing a finite result.
+
+    The power of numerical values in expressions follows Python's power, which raises an error for
+    zero to a negative power and propagates infinite and `NaN` values. Use this function when the
+    undefined cases must be reported consistently, e.g., to validate user input.
+
+    Example:
+    ```
+    >>> checked_pow(2, -1)
+    0.5
+    >>> checked_pow(0, -1)
+    ValueError: 0 ^ -1 is undefined.
+    ```
+
+    Raises:
+        ValueError: If any of the operands is not finite, the base is zero and the exponent has a
+            negative or imaginary part, or the result overflows.
+    """
+
+    if not (cmath.isfinite(base) and cmath.isfinite(exponent)):
+        raise ValueError(f"{base} ^ {exponent} is undefined for non-finite operands.")
+
+    if base == 0 and (complex(exponent).real < 0 or complex(exponent).imag != 0):
+        raise ValueError(f"{base} ^ {exponent} is undefined.")
+
+    try:
+        result = numeric_pow(base, exponent)
+    except OverflowError:
+        result = math.inf
+
+    if not cmath.isfinite(result):
+        raise ValueError(f"{base} ^ {exponent} overflows.")
+
+    return result
+
+
+class DepthExceededError(ValueError):
+    """The error raised when an expression is nested deeper than allowed.
+
+    Deeply nested expressions can exhaust the Python stack in the recursive operations. Limiting
+    the depth allows rejecting adversarial inputs, e.g., from configuration files, safely.
+    """
+
+
+def check_depth(expr: Expression, max_depth: int) -> None:
+    """Check that the expression is not nested deeper than `max_depth` levels.
+
+    A symbol or a value has depth one, and every operation adds one level, as in
+    `Expression.depth`. The depth is computed without recursion, so the check itself is safe for
+    any depth.
+
+    Raises:
+        DepthExceededError: If the expression is deeper than `max_depth`.
+    """
+
+    if expr.depth > max_depth:
+        raise DepthExceededError(f"The expression exceeds the maximum depth of {max_depth}.")
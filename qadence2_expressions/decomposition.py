@@ -0,0 +1,81 @@
+from __future__ import annotations
+
+from typing import Callable, Mapping
+
+from .core.expression import Expression
+from .core.support import Support
+from .replace import evaluate_node
+from .simplify import rewrite_bottom_up
+
+# Definitions of the gates per operator name. Each definition acts on the local qubits
+# `0, ..., n-1`, which are mapped to the `n` target qubits of the decomposed gate.
+GateLibrary = Mapping[str, Expression]
+
+
+def decompose(expr: Expression, library: GateLibrary) -> Expression:
+    """Replace the gates named in the `library` by their definitions, until only the gates
+    without definition remain.
+
+    The local qubits of a definition are mapped, in order, to the target qubits of the gate, and
+    the dagger of a gate is replaced by the dagger of its definition. The definitions can use
+    other gates of the library, as long as they don't depend on themselves.
+
+    Example:
+    ```
+    >>> library = {"H": (X(0) + Z(0)) / sqrt(2)}
+    >>> decompose(H(3), library)
+    0.7071067811865475 * X[3] + 0.7071067811865475 * Z[3]
+    ```
+
+    Raises:
+        ValueError: If a definition is cyclic, uses more qubits than the gate targets, or the
+            decomposed gate is controlled or applied to all the qubits.
+    """
+
+    expanded: dict[str, Expression] = {}
+
+    def expand(name: str, active: tuple[str, ...]) -> Expression:
+        if name in active:
+            raise ValueError(f"The definition of {name} is cyclic: {' -> '.join(active)}.")
+
+        if name not in expanded:
+            expanded[name] = rewrite_bottom_up(library[name], rule(active + (name,)))
+
+        return expanded[name]
+
+    def rule(active: tuple[str, ...]) -> Callable[[Expression], Expression]:
+        def decompose_gate(expr: Expression) -> Expression:
+            if not (expr.is_quantum_operator and expr[0].is_symbol and expr[0][0] in library):
+                return evaluate_node(expr)
+
+            support = expr[1]
+            if support.control or not support.target:
+                raise ValueError(f"Only gates on explicit targets can be decomposed, got {expr}.")
+
+            definition = _relabel_qubits(expand(expr[0][0], active), support.target)
+            return definition.dag if expr.get("is_dagger") else definition
+
+        return decompose_gate
+
+    return rewrite_bottom_up(expr, rule(()))
+
+
+def _relabel_qubits(expr: Expression, qubits: tuple[int, ...]) -> Expression:
+    def relabel(expr: Expression) -> Expression:
+        if not expr.is_quantum_operator:
+            return evaluate_node(expr)
+
+        support = expr[1]
+        if support.max_index >= len(qubits):
+            raise ValueError(f"The definition uses more than the {len(qubits)} target qubits.")
+
+        if not support.target:
+            return Expression.quantum_operator(expr[0], Support(*qubits), **expr.attrs)
+
+        support = Support(
+            target=tuple(qubits[i] for i in support.target),
+            control=tuple(qubits[i] for i in support.control),
+        )
+        return Expression.quantum_operator(expr[0], support, **expr.attrs)
+
+    return rewrite_bottom_up(expr, relabel)
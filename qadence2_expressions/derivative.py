@@ -0,0 +1,142 @@
+from __future__ import annotations
+
+import math
+from typing import Callable
+
+from .core.expression import Expression
+from .core.utils import Numeric
+from .evaluation import EvalContext, evaluate
+from .functions import cos, log, sin
+from .replace import prod, replace_core
+
+# Derivatives of the known functions with respect to their argument.
+KNOWN_DERIVATIVES: dict[str, Callable[[Expression], Expression]] = {
+    "sin": lambda arg: cos(arg),
+    "cos": lambda arg: -sin(arg),
+    "log": lambda arg: 1 / arg,
+}
+
+
+def derivative(expr: Expression, var: str) -> Expression:
+    """Returns the derivative of the expression with respect to the symbol named `var`.
+
+    Compositions of functions are differentiated with the chain rule. The exponential and the
+    square root are powers, so they are covered by the power rule. The derivatives of unknown
+    functions, array elements, and quantum operators depending on `var` are kept unevaluated as
+    `diff(expr, var)`.
+
+    Example:
+    ```
+    >>> derivative(sin(x**2), "x")
+    2.0 * cos(x ^ 2.0) * x
+    >>> derivative(function("f", x) * x, "x")
+    diff(f(x), x) * x + f(x)
+    ```
+    """
+
+    if var not in expr.free_symbols:
+        return Expression.zero()
+
+    if expr.is_symbol and not expr.is_indexed:
+        return Expression.one()
+
+    if expr.is_addition:
+        return sum(derivative(arg, var) for arg in expr.args)  # type: ignore
+
+    if expr.is_multiplication or expr.is_kronecker_product:
+        # Product rule, keeping the order of the factors.
+        terms = []
+        for i, arg in enumerate(expr.args):
+            d_arg = derivative(arg, var)
+            if not d_arg.is_zero:
+                terms.append(prod((*expr[:i], d_arg, *expr[i + 1 :])))
+        return sum(terms, Expression.zero())
+
+    if expr.is_power:
+        base, power = expr.args
+
+        if var not in power.free_symbols:
+            return power * base ** (power - 1) * derivative(base, var)  # type: ignore
+
+        if base.is_symbol and base[0] == "E":
+            return expr * derivative(power, var)
+
+        return expr * (  # type: ignore
+            derivative(power, var) * log(base) + power * derivative(base, var) / base
+        )
+
+    if expr.is_function and expr[0][0] in KNOWN_DERIVATIVES and len(expr.args) == 2:
+        arg = expr[1]
+        return KNOWN_DERIVATIVES[expr[0][0]](arg) * derivative(arg, var)
+
+    return Expression.function("diff", expr, Expression.symbol(var))
+
+
+def parameter_shift(
+    expr: Expression, var: str, shift: Numeric = math.pi / 2
+) -> tuple[Expression, Expression]:
+    """Returns the expression with the symbol named `var` shifted by `+shift` and `-shift`.
+
+    The shifted expressions are symbolic, the caller evaluates both and combines them, e.g., the
+    parameter-shift rule computes the gradient of the expectation value of a rotation as
+    `(f(var + pi/2) - f(var - pi/2)) / 2`. The attributes of the symbol, like `trainable`, are
+    preserved.
+
+    Example:
+    ```
+    >>> plus, minus = parameter_shift(RX(theta)(0), "theta")
+    >>> plus
+    RX(1.5707963267948966 + theta)[0]
+    >>> minus
+    RX(-1.5707963267948966 + theta)[0]
+    ```
+    """
+
+    symbols = [
+        subexpr
+        for subexpr in expr.subexpressions()
+        if subexpr.is_symbol and not subexpr.is_indexed and subexpr[0] == var
+    ]
+
+    plus = replace_core(expr, {symbol: symbol + shift for symbol in symbols})
+    minus = replace_core(expr, {symbol: symbol - shift for symbol in symbols})
+    return plus, minus
+
+
+def linearize_around(
+    expr: Expression, point: dict[str, Numeric], context: EvalContext | None = None
+) -> Expression:
+    """Returns the first-order approximation of the expression around the `point`.
+
+    The result is the affine expression `f(x0) + sum_i df/dx_i(x0) * (x_i - x0_i)`, where the
+    derivatives are computed symbolically and evaluated at the `point`. All the free symbols of
+    the expression must have a value in the `point`. The symbols keep their attributes, like
+    `trainable`.
+
+    Example:
+    ```
+    >>> linearize_around(x**2, {"x": 1})
+    -1.0 + 2.0 * x
+    >>> linearize_around(x * sin(y), {"x": 2, "y": 0})
+    2.0 * y
+    ```
+
+    Raises:
+        ValueError: If a symbol has no value in the `point`, or the expression or its derivatives
+            can't be numerically evaluated.
+    """
+
+    result = Expression.value(evaluate(expr, point, context))
+
+    symbols = {
+        subexpr[0]: subexpr
+        for subexpr in expr.subexpressions()
+        if subexpr.is_symbol and not subexpr.is_indexed and subexpr[0] in point
+    }
+
+    for name, symbol in sorted(symbols.items()):
+        slope = evaluate(derivative(expr, name), point, context)
+        if slope != 0:
+            result = result + slope * (symbol - point[name])
+
+    return result
@@ -0,0 +1,787 @@
+from __future__ import annotations
+
+import cmath
+import math
+import random
+import struct
+from dataclasses import dataclass, field
+from dataclasses import replace as replace_fields
+from enum import Enum
+from typing import Any, Callable, Iterable, Literal, NamedTuple, Sequence
+
+from .core.constructors import promote
+from .core.environment import Environment
+from .core.expression import Expression
+from .core.utils import Numeric, check_depth, numeric_pow
+from .replace import evaluate_node, replace
+from .simplify import rewrite_bottom_up
+from .summation import permutation_sign
+
+# Constants available by default in every `EvalContext`, indexed by the symbols' names.
+CONSTANTS: dict[str, Numeric] = {"E": math.e, "I": 1j, "pi": math.pi}
+
+
+def real_or_complex(
+    real_fn: Callable[[float], float], complex_fn: Callable[[complex], complex]
+) -> Callable[[Numeric], Numeric]:
+    """Use the real version of a function when possible, falling back to the complex one."""
+
+    def fn(x: Numeric) -> Numeric:
+        if isinstance(x, complex):
+            return complex_fn(x)
+        try:
+            return real_fn(x)
+        except ValueError:
+            return complex_fn(x)
+
+    return fn
+
+
+def real_cbrt(x: float) -> float:
+    """Returns the real cube root of `x`, negative for negative numbers."""
+
+    return math.copysign(abs(x) ** (1 / 3), x)
+
+
+def to_single(x: Numeric) -> Numeric:
+    """Round a numerical value to the nearest single precision (32-bit) floating point value."""
+
+    if isinstance(x, complex):
+        return complex(to_single(x.real), to_single(x.imag))
+
+    return struct.unpack("f", struct.pack("f", x))[0]  # type: ignore
+
+
+def with_keywords(fn: Callable[..., Any], keywords: tuple[str, ...]) -> Callable[..., Any]:
+    """Adapt `fn` to receive its last arguments by name, as stored in function expressions."""
+
+    if not keywords:
+        return fn
+
+    def call(*args: Any) -> Any:
+        positional = len(args) - len(keywords)
+        return fn(*args[:positional], **dict(zip(keywords, args[positional:])))
+
+    return call
+
+
+BUILTIN_FUNCTIONS: dict[str, Callable[..., Numeric]] = {
+    "sin": real_or_complex(math.sin, cmath.sin),
+    "cos": real_or_complex(math.cos, cmath.cos),
+    "log": real_or_complex(math.log, cmath.log),
+    "log10": real_or_complex(math.log10, cmath.log10),
+    "exp2": lambda x: 2**x,
+    "cbrt": lambda x: x ** (1 / 3) if isinstance(x, complex) else real_cbrt(x),
+    "sinh": real_or_complex(math.sinh, cmath.sinh),
+    "cosh": real_or_complex(math.cosh, cmath.cosh),
+    "tanh": real_or_complex(math.tanh, cmath.tanh),
+    "atan": real_or_complex(math.atan, cmath.atan),
+    "abs": abs,
+    "conj": lambda x: x.conjugate(),
+    "re": lambda x: x.real,
+    "im": lambda x: x.imag,
+    "floordiv": lambda x, y: x // y,
+    "mod": lambda x, y: x % y,
+}
+
+# Real-valued versions of the built-in functions, used by `compile_closure`.
+REAL_BUILTIN_FUNCTIONS: dict[str, Callable[..., float]] = {
+    "sin": math.sin,
+    "cos": math.cos,
+    "log": math.log,
+    "log10": math.log10,
+    "exp2": lambda x: 2.0**x,
+    "cbrt": real_cbrt,
+    "sinh": math.sinh,
+    "cosh": math.cosh,
+    "tanh": math.tanh,
+    "atan": math.atan,
+    "abs": abs,
+    "conj": lambda x: x,
+    "re": lambda x: x,
+    "im": lambda x: 0.0,
+    "floordiv": lambda x, y: x // y,
+    "mod": lambda x, y: x % y,
+}
+
+# Classical functions known to be real-valued for real arguments. The logarithms are not included,
+# as they are complex for negative arguments.
+REAL_FUNCTIONS = {"sin", "cos", "sinh", "cosh", "tanh", "atan", "exp2", "cbrt", "abs", "re", "im"}
+
+
+# Values of the index symbols created by `kronecker_delta` and `levi_civita`. They are looked up
+# only for the functions marked with the `index_symbol` attribute, so user functions with the same
+# names are not affected.
+INDEX_SYMBOLS: dict[str, Callable[..., float]] = {
+    "delta": lambda i, j: float(i == j),
+    "epsilon": lambda *indices: float(permutation_sign(indices)),
+}
+
+
+@dataclass
+class EvalContext:
+    """Holds the user-defined functions and constants available during the numerical evaluation.
+
+    The functions take the evaluated arguments and return a numerical value. They take
+    precedence over the built-in functions with the same name.
+
+    The `constants` are the values of symbols that are not free parameters, like `pi`. They are
+    used when no value is given for the symbol. Each context has its own copy, initialised with
+    the Euler's number `E`, the imaginary unit `I`, and `pi`, so adding or removing constants
+    doesn't affect other contexts.
+
+    The `precision` sets the floating point format used by the evaluation. Expressions always
+    store their values in double precision. With `"single"` precision, the values, constants, and
+    the result of every operation are rounded to single precision, emulating the evaluation on
+    32-bit hardware. There is no mixed precision arithmetic: every value is converted to the
+    chosen format when read.
+
+    The `max_depth` limits the nesting of the expressions accepted by `evaluate`, `partial_evaluate`
+    and the compilation, which raise a `DepthExceededError` instead of exhausting the stack on
+    deeply nested inputs.
+
+    Example:
+    ```
+    >>> context = EvalContext()
+    >>> context.register("double", lambda x: 2 * x)
+    >>> evaluate(function("double", value(5)), context=context)
+    10.0
+    ```
+    """
+
+    functions: dict[str, Callable[..., Numeric]] = field(default_factory=dict)
+    precision: Literal["double", "single"] = "double"
+    constants: dict[str, Numeric] = field(default_factory=lambda: dict(CONSTANTS))
+    max_depth: int | None = None
+
+    def cast(self, x: Numeric) -> Numeric:
+        """Convert the value `x` to the floating point format set by `precision`."""
+        return to_single(x) if self.precision == "single" else x
+
+    def register(self, name: str, fn: Callable[..., Numeric]) -> None:
+        """Make the function `fn` available under `name`."""
+        self.functions[name] = fn
+
+    def lookup(self, name: str) -> Callable[..., Numeric]:
+        """Returns the implementation of the function `name`.
+
+        Raises:
+            ValueError: If the function is neither registered nor a built-in one.
+        """
+
+        if name in self.functions:
+            return self.functions[name]
+
+        if name in BUILTIN_FUNCTIONS:
+            return BUILTIN_FUNCTIONS[name]
+
+        raise ValueError(f"Unknown function '{name}'.")
+
+    def function_of(self, expr: Expression) -> Callable[..., Numeric]:
+        """Returns the implementation of the function expression `expr`, taking its arguments in
+        order, including the named ones.
+
+        Raises:
+            ValueError: If the function is neither registered nor a built-in one.
+        """
+
+        if expr.get("index_symbol"):
+            return INDEX_SYMBOLS[expr[0][0]]
+
+        return with_keywords(self.lookup(expr[0][0]), expr.get("keywords", ()))
+
+
+def evaluate(
+    expr: Expression,
+    values: dict[str, Numeric] | None = None,
+    context: EvalContext | None = None,
+) -> Numeric:
+    """Numerically evaluate an expression.
+
+    Example:
+    ```
+    >>> evaluate(2 * x + sin(y), {"x": 1, "y": 0})
+    2.0
+    ```
+
+    Args:
+        expr: The expression to evaluate.
+        values: The numerical values of the symbols, indexed by their names.
+        context: Provides user-defined functions and sets the floating point precision.
+
+    Returns:
+        The numerical result of the expression.
+
+    Raises:
+        ValueError: If a symbol has no value, a function is unknown, or the expression contains
+            quantum operators or undefined values, including the quotient `0 / 0` of evaluated
+            values.
+        ZeroDivisionError: If a nonzero value is divided by zero.
+        DepthExceededError: If the expression is deeper than the `max_depth` of the context.
+    """
+
+    values = values or {}
+    context = context or EvalContext()
+
+    # The depth is checked once, before the recursion.
+    if context.max_depth is not None:
+        check_depth(expr, context.max_depth)
+        context = replace_fields(context, max_depth=None)
+
+    if expr.is_value:
+        return context.cast(expr[0])  # type: ignore
+
+    if expr.is_undefined:
+        raise ValueError("Cannot evaluate an undefined expression.")
+
+    if expr.is_indexed:
+        index = expr.get("index")
+        if isinstance(index, Expression):
+            index = int(evaluate(index, values, context).real)
+        if expr[0] not in values:
+            raise ValueError(f"The symbol '{expr[0]}' has no value.")
+        return context.cast(values[expr[0]][index])  # type: ignore
+
+    if expr.is_symbol:
+        name = expr[0]
+        if name in values:
+            return context.cast(values[name])
+        if name in context.constants:
+            return context.cast(context.constants[name])
+        raise ValueError(f"The symbol '{name}' has no value.")
+
+    if expr.is_function:
+        fn = context.function_of(expr)
+        args = [evaluate(promote(arg), values, context) for arg in expr[1:]]
+        return context.cast(fn(*args))
+
+    if expr.is_addition:
+        return context.cast(sum(evaluate(arg, values, context) for arg in expr.args))
+
+    if expr.is_multiplication:
+        factors = []
+        division_by_zero = None
+        for arg in expr.args:
+            try:
+                factors.append(evaluate(arg, values, context))
+            except ZeroDivisionError as error:
+                division_by_zero = error
+
+        # A division by zero is undefined when the numerator is also zero, as in `0 / 0`.
+        if division_by_zero is not None:
+            if any(factor == 0 for factor in factors):
+                raise ValueError("Cannot evaluate an undefined expression.") from division_by_zero
+            raise division_by_zero
+
+        return context.cast(math.prod(factors))
+
+    if expr.is_power:
+        base = evaluate(expr[0], values, context)
+        power = evaluate(expr[1], values, context)
+        return context.cast(numeric_pow(base, power))
+
+    raise ValueError(f"The expression {expr} cannot be numerically evaluated.")
+
+
+def parameters(expr: Expression, context: EvalContext | None = None) -> list[str]:
+    """Returns the names of the free symbols of the expression in a stable (sorted) order.
+
+    Protected symbols, like the Euler's number `E`, and the constants of the `context`, like
+    `pi`, are not considered free parameters.
+
+    Example:
+    ```
+    >>> parameters(theta * X(0) + alpha)
+    ['alpha', 'theta']
+    ```
+    """
+
+    context = context or EvalContext()
+    return sorted(expr.free_symbols - Environment.protected - context.constants.keys())
+
+
+def probably_equal(
+    expr: Expression,
+    other: Expression,
+    samples: int = 16,
+    seed: int = 0,
+    tolerance: float = 1e-9,
+    context: EvalContext | None = None,
+) -> bool:
+    """Check whether two expressions are equal by evaluating them at random points.
+
+    This is a probabilistic check: a `False` result proves the expressions are different, while
+    `True` only means they agreed on all the `samples`, drawn uniformly from `[-2, 2]` for each
+    free symbol of either expression. Use it when the symbolic comparison, e.g.,
+    `is_identically_zero`, can't decide, like for trigonometric identities.
+
+    Example:
+    ```
+    >>> probably_equal((x + 1) ** 2, x**2 + 2 * x + 1)
+    True
+    >>> probably_equal(sin(x) ** 2 + cos(x) ** 2, value(1))
+    True
+    >>> probably_equal(x**2, x**3)
+    False
+    ```
+
+    Args:
+        expr: The first expression.
+        other: The second expression.
+        samples: The number of random points.
+        seed: The seed of the random generator, to make the check reproducible.
+        tolerance: The relative and absolute tolerance of the comparison.
+        context: Provides user-defined functions and constants.
+
+    Raises:
+        ValueError: If any of the expressions can't be numerically evaluated.
+    """
+
+    context = context or EvalContext()
+    names = sorted(set(parameters(expr, context)) | set(parameters(other, context)))
+    rng = random.Random(seed)
+
+    for _ in range(samples):
+        values: dict[str, Numeric] = {name: rng.uniform(-2, 2) for name in names}
+        lhs, rhs = evaluate(expr, values, context), evaluate(other, values, context)
+        if not cmath.isclose(lhs, rhs, rel_tol=tolerance, abs_tol=tolerance):
+            return False
+
+    return True
+
+
+def partial_evaluate(
+    expr: Expression, fixed: dict[str, Numeric], context: EvalContext | None = None
+) -> Expression:
+    """Bind some of the symbols to numerical values and fold the resulting constant subexpressions.
+
+    The subexpressions that no longer depend on free symbols are replaced by their numerical
+    values, while the remaining symbolic structure is kept. This avoids recomputing the constant
+    part of an expression when it is evaluated many times for different values of the remaining
+    symbols.
+
+    Example:
+    ```
+    >>> partial_evaluate(x * y + sin(x) + 3, {"x": 0.5})
+    3.479425538604203 + 0.5 * y
+    ```
+
+    Args:
+        expr: The expression to evaluate.
+        fixed: The numerical values of the symbols to bind, indexed by their names.
+        context: Provides user-defined functions.
+
+    Returns:
+        An expression depending only on the symbols not present in `fixed`. Subexpressions with
+        unknown functions or quantum operators are not folded.
+
+    Raises:
+        DepthExceededError: If the expression is deeper than the `max_depth` of the context.
+    """
+
+    context = context or EvalContext()
+
+    if context.max_depth is not None:
+        check_depth(expr, context.max_depth)
+        context = replace_fields(context, max_depth=None)
+
+    rules = {
+        subexpr: Expression.value(fixed[subexpr[0]])
+        for subexpr in expr.subexpressions()
+        if subexpr.is_symbol and not subexpr.is_indexed and subexpr[0] in fixed
+    }
+
+    def fold(expr: Expression) -> Expression:
+        if expr.is_value or expr.subspace is not None or parameters(expr, context):
+            return evaluate_node(expr)
+
+        try:
+            return Expression.value(evaluate(expr, context=context))
+        except ValueError:
+            return expr
+
+    return rewrite_bottom_up(replace(expr, rules), fold)
+
+
+def substitute_value(
+    expr: Expression, name: str, x: Numeric, context: EvalContext | None = None
+) -> Expression:
+    """Bind the symbol `name` to the numerical value `x`, folding the constant subexpressions.
+
+    A lighter alternative to `partial_evaluate` for the common case of a single symbol, e.g., in
+    optimisation loops. The substitution and the folding happen in the same traversal.
+
+    Example:
+    ```
+    >>> substitute_value(x**2 + 1, "x", 3)
+    10.0
+    >>> substitute_value(x**2 + y, "x", 3)
+    9.0 + y
+    ```
+
+    Args:
+        expr: The expression to evaluate.
+        name: The name of the symbol to bind.
+        x: The numerical value of the symbol.
+        context: Provides user-defined functions used to fold the calls with constant arguments.
+    """
+
+    context = context or EvalContext()
+    bound = Expression.value(x)
+
+    def bind(expr: Expression) -> Expression:
+        if expr.is_symbol and not expr.is_indexed and expr[0] == name:
+            return bound
+
+        if expr.is_function and all(promote(arg).is_value for arg in expr[1:]):
+            try:
+                fn = context.function_of(expr)
+            except ValueError:
+                return expr
+            return Expression.value(context.cast(fn(*(promote(arg)[0] for arg in expr[1:]))))
+
+        # The arguments of parametric operators are not visited by `rewrite_bottom_up`.
+        if expr.is_quantum_operator and expr[0].is_function:
+            args = tuple(rewrite_bottom_up(arg, bind) for arg in expr[0][1:])
+            if any(new is not old for new, old in zip(args, expr[0][1:])):
+                operator = Expression(expr[0].head, expr[0][0], *args, **expr[0].attrs)
+                return Expression.quantum_operator(operator, expr[1], **expr.attrs)
+            return expr
+
+        return evaluate_node(expr)
+
+    return rewrite_bottom_up(expr, bind)
+
+
+def evaluate_vec(
+    expr: Expression, params: Sequence[Numeric], context: EvalContext | None = None
+) -> Numeric:
+    """Numerically evaluate an expression binding the values positionally.
+
+    The `params` are matched with the symbols in the order given by `parameters(expr)`, which
+    decouples the arrays used by optimisers from the symbols' names.
+
+    Example:
+    ```
+    >>> evaluate_vec(theta * phi + alpha, [1, 2, 3])  # alpha=1, phi=2, theta=3
+    7.0
+    ```
+
+    Raises:
+        ValueError: If the number of values doesn't match the number of free parameters.
+    """
+
+    names = parameters(expr, context)
+    if len(names) != len(params):
+        raise ValueError(f"Expected {len(names)} parameters, got {len(params)}.")
+
+    return evaluate(expr, dict(zip(names, params)), context)
+
+
+def evaluate_over_time(
+    expr: Expression,
+    time_symbol: str,
+    times: Iterable[float],
+    values: dict[str, Numeric] | None = None,
+    context: EvalContext | None = None,
+) -> list[Numeric]:
+    """Numerically evaluate an expression at each of the `times`, e.g., to sample a pulse.
+
+    The other symbols are bound once with `partial_evaluate`, and the remaining expression is
+    compiled with `compile_expression` before the sweep over the time values.
+
+    Example:
+    ```
+    >>> evaluate_over_time(amplitude * sin(t), "t", [0, pi / 2], {"amplitude": 2})
+    [0.0, 2.0]
+    ```
+
+    Args:
+        expr: The expression to evaluate.
+        time_symbol: The name of the time symbol.
+        times: The values of the time symbol to evaluate the expression at.
+        values: The numerical values of the other symbols, indexed by their names.
+        context: Provides user-defined functions and sets the floating point precision.
+
+    Raises:
+        ValueError: If any symbol other than `time_symbol` is not given a value.
+    """
+
+    context = context or EvalContext()
+    fixed = {name: x for name, x in (values or {}).items() if name != time_symbol}
+    residual = partial_evaluate(expr, fixed, context)
+
+    missing = [name for name in parameters(residual, context) if name != time_symbol]
+    if missing:
+        raise ValueError(f"Missing values for the symbols {missing}.")
+
+    compiled = compile_expression(residual, context)
+    if not compiled.parameters:
+        constant = compiled([])
+        return [constant for _ in times]
+
+    return [compiled([t]) for t in times]
+
+
+# Cost of a function call, or a non-integer power, relative to a single arithmetic operation.
+FUNCTION_COST = 10
+
+
+def eval_cost(expr: Expression) -> int:
+    """Estimate the number of arithmetic operations needed to evaluate the expression.
+
+    An addition or multiplication of `k` terms costs `k - 1` operations. An integer power costs
+    the bit length of its exponent, as computed by repeated squaring, and function calls and other
+    powers have a fixed cost of `FUNCTION_COST`. Repeated subexpressions are counted every time.
+    This is a static estimate, useful to compare expressions or to choose an evaluation strategy.
+
+    Example:
+    ```
+    >>> eval_cost(x**8 + y)
+    5
+    ```
+
+    Raises:
+        ValueError: If the expression contains quantum operators.
+    """
+
+    if expr.is_value or expr.is_symbol:
+        return 0
+
+    if expr.is_function:
+        return FUNCTION_COST + sum(eval_cost(promote(arg)) for arg in expr[1:])
+
+    if expr.is_addition or expr.is_multiplication:
+        return len(expr.args) - 1 + sum(eval_cost(arg) for arg in expr.args)
+
+    if expr.is_power:
+        base, power = expr.args
+        if power.is_value and not isinstance(power[0], complex) and float(power[0]).is_integer():
+            return int(abs(power[0])).bit_length() + eval_cost(base)
+        return FUNCTION_COST + eval_cost(base) + eval_cost(power)
+
+    raise ValueError(f"The expression {expr} cannot be numerically evaluated.")
+
+
+class OpCode(Enum):
+    """The operations of the stack machine used by `CompiledExpression`."""
+
+    CONST = "const"  # Push a constant.
+    LOAD = "load"  # Push the parameter in the given slot.
+    ADD = "add"  # Pop `n` values and push their sum.
+    MUL = "mul"  # Pop `n` values and push their product.
+    POW = "pow"  # Pop the power and the base, and push the exponentiation.
+    CALL = "call"  # Pop `n` values and push the result of the function applied to them.
+
+
+class Instruction(NamedTuple):
+    opcode: OpCode
+    operand: Any = None
+    arity: int = 0
+
+
+class CompiledExpression:
+    """An expression flattened into a sequence of stack machine instructions.
+
+    The evaluation doesn't traverse the expression tree, which makes it suitable for evaluating
+    the same expression many times. The parameters are bound positionally in the order given by
+    `parameters(expr)`.
+
+    Example:
+    ```
+    >>> compiled = compile_expression(theta * x + sin(x))
+    >>> compiled.parameters
+    ['theta', 'x']
+    >>> compiled([2, 0])
+    0.0
+    ```
+    """
+
+    def __init__(
+        self,
+        instructions: list[Instruction],
+        parameters: list[str],
+        cast: Callable[[Numeric], Numeric] | None = None,
+    ) -> None:
+        self.instructions = instructions
+        self.parameters = parameters
+        # Converts the parameters and intermediate results to the evaluation precision.
+        self.cast = cast or (lambda x: x)
+
+    def __call__(self, params: Sequence[Numeric]) -> Numeric:
+        if len(params) != len(self.parameters):
+            raise ValueError(f"Expected {len(self.parameters)} parameters, got {len(params)}.")
+
+        cast = self.cast
+        stack: list[Numeric] = []
+
+        for opcode, operand, arity in self.instructions:
+            if opcode == OpCode.CONST:
+                stack.append(operand)
+
+            elif opcode == OpCode.LOAD:
+                stack.append(cast(params[operand]))
+
+            elif opcode == OpCode.POW:
+                power = stack.pop()
+                stack.append(cast(numeric_pow(stack.pop(), power)))
+
+            else:
+                # The arguments are the top `arity` entries, possibly none.
+                start = len(stack) - arity
+                args = stack[start:]
+                del stack[start:]
+
+                if opcode == OpCode.ADD:
+                    stack.append(cast(sum(args)))
+                elif opcode == OpCode.MUL:
+                    stack.append(cast(math.prod(args)))
+                else:
+                    stack.append(cast(operand(*args)))
+
+        return stack.pop()
+
+
+def compile_expression(expr: Expression, context: EvalContext | None = None) -> CompiledExpression:
+    """Compile an expression into a `CompiledExpression` for fast repeated evaluation.
+
+    The functions are resolved at compilation time using the `context`, which also sets the
+    floating point precision of the evaluation.
+
+    Raises:
+        ValueError: If a function is unknown or the expression contains quantum operators or
+            undefined values.
+        DepthExceededError: If the expression is deeper than the `max_depth` of the context.
+    """
+
+    context = context or EvalContext()
+
+    if context.max_depth is not None:
+        check_depth(expr, context.max_depth)
+    names = parameters(expr, context)
+    slots = {name: i for i, name in enumerate(names)}
+    instructions: list[Instruction] = []
+
+    def emit(expr: Expression) -> None:
+        if expr.is_value:
+            instructions.append(Instruction(OpCode.CONST, context.cast(expr[0])))
+
+        elif expr.is_undefined:
+            raise ValueError("Cannot evaluate an undefined expression.")
+
+        elif expr.is_indexed:
+            raise ValueError(f"Array elements, like {expr}, cannot be compiled.")
+
+        elif expr.is_symbol:
+            if expr[0] in slots:
+                instructions.append(Instruction(OpCode.LOAD, slots[expr[0]]))
+            else:
+                constant = context.cast(context.constants[expr[0]])
+                instructions.append(Instruction(OpCode.CONST, constant))
+
+        elif expr.is_function:
+            fn = context.function_of(expr)
+            for arg in expr[1:]:
+                emit(promote(arg))
+            instructions.append(Instruction(OpCode.CALL, fn, len(expr.args) - 1))
+
+        elif expr.is_addition or expr.is_multiplication:
+            for arg in expr.args:
+                emit(arg)
+            opcode = OpCode.ADD if expr.is_addition else OpCode.MUL
+            instructions.append(Instruction(opcode, arity=len(expr.args)))
+
+        elif expr.is_power:
+            emit(expr[0])
+            emit(expr[1])
+            instructions.append(Instruction(OpCode.POW))
+
+        else:
+            raise ValueError(f"The expression {expr} cannot be numerically evaluated.")
+
+    emit(expr)
+    return CompiledExpression(instructions, names, context.cast)
+
+
+def compile_closure(
+    expr: Expression, params: Sequence[str], context: EvalContext | None = None
+) -> Callable[[Sequence[float]], float]:
+    """Compile a real-valued expression into a Python function of the parameters' values.
+
+    The expression is traversed once to build nested closures, so evaluating the result involves
+    neither the expression tree nor a dictionary of values. The returned function takes a sequence
+    of floats matched positionally with `params`. As in `evaluate`, the parameters, constants, and
+    the result of every operation are rounded to the `precision` of the context.
+
+    Example:
+    ```
+    >>> fn = compile_closure(theta * x + sin(x), ["x", "theta"])
+    >>> fn([0, 2])
+    0.0
+    ```
+
+    Raises:
+        ValueError: If the expression contains complex values, quantum operators, unknown
+            functions, or symbols not listed in `params`. Operations with a complex result, like
+            the square root of a negative number, raise a `ValueError` when evaluated.
+        DepthExceededError: If the expression is deeper than the `max_depth` of the context.
+    """
+
+    context = context or EvalContext()
+
+    if context.max_depth is not None:
+        check_depth(expr, context.max_depth)
+    slots = {name: i for i, name in enumerate(params)}
+
+    def rounded(fn: Callable[[Sequence[float]], float]) -> Callable[[Sequence[float]], float]:
+        # Skip the extra call when the values are kept in double precision.
+        if context.precision == "single":
+            return lambda xs: to_single(fn(xs))
+        return fn
+
+    def build(expr: Expression) -> Callable[[Sequence[float]], float]:
+        if expr.is_value:
+            if isinstance(expr[0], complex):
+                raise ValueError(f"Complex values, like {expr}, cannot be compiled to a closure.")
+            constant = float(context.cast(expr[0]))
+            return lambda _: constant
+
+        if expr.is_indexed:
+            raise ValueError(f"Array elements, like {expr}, cannot be compiled.")
+
+        if expr.is_symbol:
+            if expr[0] in slots:
+                slot = slots[expr[0]]
+                return rounded(lambda xs: xs[slot])
+            if expr[0] in context.constants:
+                constant = context.constants[expr[0]]
+                if isinstance(constant, complex):
+                    raise ValueError(f"The constant '{expr[0]}' is complex.")
+                constant = float(context.cast(constant))
+                return lambda _: constant
+            raise ValueError(f"The symbol '{expr[0]}' is not a parameter.")
+
+        if expr.is_function:
+            name = expr[0][0]
+            if name in context.functions or name not in REAL_BUILTIN_FUNCTIONS:
+                fn = context.function_of(expr)
+            else:
+                fn = with_keywords(REAL_BUILTIN_FUNCTIONS[name], expr.get("keywords", ()))
+            args = [build(promote(arg)) for arg in expr[1:]]
+            return rounded(lambda xs: fn(*(arg(xs) for arg in args)))
+
+        if expr.is_addition:
+            terms = [build(arg) for arg in expr.args]
+            return rounded(lambda xs: sum(term(xs) for term in terms))
+
+        if expr.is_multiplication:
+            factors = [build(arg) for arg in expr.args]
+            return rounded(lambda xs: math.prod(factor(xs) for factor in factors))
+
+        if expr.is_power:
+            base, power = build(expr[0]), build(expr[1])
+            return rounded(lambda xs: math.pow(base(xs), power(xs)))
+
+        raise ValueError(f"The expression {expr} cannot be numerically evaluated.")
+
+    return build(expr)
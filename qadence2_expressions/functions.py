@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from typing import Iterable
+
 from qadence2_expressions import (
     Expression,
     function,
@@ -7,6 +9,12 @@ from qadence2_expressions import (
     Numeric,
 )
 
+from .evaluation import REAL_FUNCTIONS
+
+# Largest integer exponent expanded by `split_real_imag`. The expansion multiplies the parts once
+# per unit of the exponent, so larger powers are kept as `re(...)` and `im(...)`.
+MAX_EXPANDED_POWER = 8
+
 
 def sin(x: Expression | Numeric) -> Expression:
     return function("sin", promote(x))
@@ -16,6 +24,22 @@ def cos(x: Expression | Numeric) -> Expression:
     return function("cos", promote(x))
 
 
+def sinh(x: Expression | Numeric) -> Expression:
+    return function("sinh", promote(x))
+
+
+def cosh(x: Expression | Numeric) -> Expression:
+    return function("cosh", promote(x))
+
+
+def tanh(x: Expression | Numeric) -> Expression:
+    return function("tanh", promote(x))
+
+
+def atan(x: Expression | Numeric) -> Expression:
+    return function("atan", promote(x))
+
+
 # Exponential function as power.
 def exp(x: Expression | Numeric) -> Expression:
     return Expression.symbol("E") ** promote(x)
@@ -27,6 +51,150 @@ def log(x: Expression | Numeric) -> Expression:
     return expr.as_quantum_operator()
 
 
+def exp2(x: Expression | Numeric) -> Expression:
+    return function("exp2", promote(x))
+
+
+def log10(x: Expression | Numeric) -> Expression:
+    return function("log10", promote(x))
+
+
 # Using square root as power makes symbolic simplifications easier.
 def sqrt(x: Expression | Numeric) -> Expression:
     return promote(x) ** 0.5
+
+
+# Unlike the power `x^(1/3)`, the cube root of a negative real number is real.
+def cbrt(x: Expression | Numeric) -> Expression:
+    return function("cbrt", promote(x))
+
+
+# Complex conjugation of classical expressions. Unlike `dag`, symbols are not assumed to be real.
+def conj(x: Expression | Numeric) -> Expression:
+    expr = promote(x)
+
+    if expr.subspace is not None:
+        raise TypeError("Use `dag` to conjugate quantum operators.")
+
+    if expr.is_value:
+        return Expression.value(expr[0].conjugate())
+
+    if expr.is_addition:
+        return sum((conj(arg) for arg in expr.args), Expression.zero())
+
+    if expr.is_multiplication:
+        result = Expression.one()
+        for arg in expr.args:
+            result = result * conj(arg)
+        return result
+
+    if expr.is_function and expr[0][0] == "conj":
+        return expr[1]  # type: ignore [no-any-return]
+
+    return function("conj", expr)
+
+
+def split_real_imag(
+    expr: Expression, complex_symbols: Iterable[str] = ()
+) -> tuple[Expression, Expression]:
+    """Split a classical expression into its real and imaginary parts, `expr = re + i * im`.
+
+    The symbols are taken as real, except those named in `complex_symbols`, which are split into
+    `re(z) + i * im(z)`. Products and integer powers up to `MAX_EXPANDED_POWER` are expanded
+    using `i^2 = -1`, the exponentials with Euler's formula, and `conj` flips the sign of the
+    imaginary part. The parts of the subexpressions that can't be separated, like the non-integer
+    or larger powers of complex values, are kept as `re(...)` and `im(...)`.
+
+    Example:
+    ```
+    >>> re, im = split_real_imag((x + 1j * y) ** 2)
+    >>> re
+    x ^ 2.0 - y ^ 2.0
+    >>> im
+    2.0 * x * y
+    >>> split_real_imag(conj(z) * x, complex_symbols=["z"])[1]
+    -im(z) * x
+    ```
+
+    Raises:
+        TypeError: If the expression contains quantum operators.
+    """
+
+    if expr.subspace is not None:
+        raise TypeError("Only classical expressions can be split into real and imaginary parts.")
+
+    return _split(expr, set(complex_symbols))
+
+
+def _split(expr: Expression, complex_symbols: set[str]) -> tuple[Expression, Expression]:
+    if expr.is_value:
+        number = complex(expr[0])
+        return Expression.value(number.real), Expression.value(number.imag)
+
+    if expr.is_symbol:
+        if expr[0] in complex_symbols:
+            return function("re", expr), function("im", expr)
+        return expr, Expression.zero()
+
+    if expr.is_addition:
+        parts = [_split(arg, complex_symbols) for arg in expr.args]
+        return (
+            sum((re for re, _ in parts), Expression.zero()),
+            sum((im for _, im in parts), Expression.zero()),
+        )
+
+    if expr.is_multiplication:
+        result = (Expression.one(), Expression.zero())
+        for arg in expr.args:
+            result = _multiply(result, _split(arg, complex_symbols))
+        return result
+
+    if expr.is_function and expr[0][0] == "conj" and len(expr.args) == 2:
+        re, im = _split(expr[1], complex_symbols)
+        return re, -im
+
+    if expr.is_function:
+        # The absolute value and the parts of a complex number are real for any argument.
+        if expr[0][0] in ("abs", "re", "im"):
+            return expr, Expression.zero()
+
+        args = [_split(arg, complex_symbols) for arg in expr[1:]]
+        if expr[0][0] in REAL_FUNCTIONS and all(im.is_zero for _, im in args):
+            return expr, Expression.zero()
+
+    if expr.is_power:
+        base, power = expr.args
+        re, im = _split(base, complex_symbols)
+
+        integer = (
+            power.is_value and not isinstance(power[0], complex) and float(power[0]).is_integer()
+        )
+        if integer and im.is_zero:
+            return expr, Expression.zero()
+
+        if integer and abs(power[0]) <= MAX_EXPANDED_POWER:
+            # The negative powers are the positive powers of the inverse, `(re - i im) / |z|^2`.
+            if power[0] < 0:
+                norm = re**2 + im**2
+                re, im = re / norm, -im / norm
+
+            result = (Expression.one(), Expression.zero())
+            for _ in range(abs(int(power[0]))):
+                result = _multiply(result, (re, im))
+            return result
+
+        # Euler's formula, `e^(a + i b) = e^a (cos(b) + i sin(b))`.
+        if base.is_symbol and base[0] == "E":
+            a, b = _split(power, complex_symbols)
+            if b.is_zero:
+                return expr, Expression.zero()
+            return exp(a) * cos(b), exp(a) * sin(b)
+
+    return function("re", expr), function("im", expr)
+
+
+def _multiply(
+    lhs: tuple[Expression, Expression], rhs: tuple[Expression, Expression]
+) -> tuple[Expression, Expression]:
+    (a, b), (c, d) = lhs, rhs
+    return a * c - b * d, a * d + b * c
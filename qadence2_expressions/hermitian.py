@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+from typing import Iterable
+
+from .core.expression import Expression
+from .evaluation import REAL_FUNCTIONS
+from .simplify import rewrite_bottom_up
+
+
+def is_hermitian(expr: Expression, hermitian_operators: Iterable[str] = ()) -> bool | None:
+    """Check whether an expression is Hermitian by comparing it with its dagger.
+
+    Quantum operators flagged with `is_hermitian`, like the Pauli operators, and the operators
+    named in `hermitian_operators` are taken as Hermitian. Symbols are assumed to be real
+    parameters. The check is structural, so Hermitian expressions written in a non-canonical way
+    may not be recognised.
+
+    Example:
+    ```
+    >>> is_hermitian(X(0) + Z(0))
+    True
+    >>> is_hermitian(1j * X(0))
+    False
+    >>> is_hermitian(function("f", x) * X(0))
+    None
+    ```
+
+    Args:
+        expr: The expression to check.
+        hermitian_operators: Names of additional operators known to be Hermitian.
+
+    Returns:
+        Whether the expression is Hermitian, or `None` if it contains classical functions that
+        are not known to be real-valued.
+    """
+
+    hermitian_operators = set(hermitian_operators)
+
+    def normalise(expr: Expression) -> Expression:
+        if not expr.is_quantum_operator:
+            return expr
+
+        attrs = {k: v for k, v in expr.attrs.items() if not (k == "is_dagger" and not v)}
+        if expr[0].is_symbol and expr[0][0] in hermitian_operators:
+            attrs["is_hermitian"] = True
+
+        return Expression(expr.head, *expr.args, **attrs)
+
+    if not has_real_coefficients(expr):
+        return None
+
+    expr = rewrite_bottom_up(expr, normalise)
+    return rewrite_bottom_up(expr.dag, normalise) == expr
+
+
+def has_real_coefficients(expr: Expression) -> bool:
+    """Returns true if all the classical functions in the expression are known to be real."""
+
+    if expr.is_function:
+        return expr[0][0] in REAL_FUNCTIONS and all(
+            isinstance(arg, Expression)
+            and arg.subspace is None
+            and has_real_coefficients(arg)
+            and arg.dag == arg
+            for arg in expr[1:]
+        )
+
+    # The parameters of parametric operators are handled by the dagger of the operator.
+    if expr.is_quantum_operator:
+        return expr[0].is_symbol or expr[0].is_function or has_real_coefficients(expr[0])
+
+    return all(has_real_coefficients(arg) for arg in expr.args if isinstance(arg, Expression))
@@ -0,0 +1,167 @@
+from __future__ import annotations
+
+import math
+from typing import Callable
+
+from .core.expression import Expression
+from .evaluation import CONSTANTS, real_cbrt
+
+# A closed interval of real numbers, `(lower, upper)`.
+Interval = tuple[float, float]
+
+# Functions increasing over their whole domain.
+INCREASING_FUNCTIONS: dict[str, Callable[[float], float]] = {
+    "log": math.log,
+    "log10": math.log10,
+    "exp2": lambda x: 2.0**x,
+    "cbrt": real_cbrt,
+    "sinh": math.sinh,
+    "tanh": math.tanh,
+    "atan": math.atan,
+}
+
+# Functions defined only for positive arguments.
+POSITIVE_DOMAIN = {"log", "log10"}
+
+
+def evaluate_interval(expr: Expression, bounds: dict[str, Interval]) -> Interval:
+    """Returns bounds of the values of a real expression when its symbols vary in the `bounds`.
+
+    The bounds are computed with interval arithmetic, so they are conservative: the expression
+    takes values inside them, but they can be wider than the exact range when a symbol appears
+    several times, e.g., `x * (1 - x)` over `[0, 1]` gives `[-1, 1]`. Sums, products, powers with
+    constant exponents, the exponential, and the built-in real functions are supported.
+
+    Example:
+    ```
+    >>> evaluate_interval(x**2, {"x": (-1, 2)})
+    (0.0, 4.0)
+    >>> evaluate_interval(2 * x + sin(y), {"x": (0, 1), "y": (0, pi)})
+    (0.0, 3.0)
+    ```
+
+    Raises:
+        ValueError: If a symbol has no bounds, the expression contains complex values, quantum
+            operators, or unsupported functions, or the interval of an argument exceeds the
+            domain of its function, e.g., for a division by an interval containing zero.
+    """
+
+    if expr.is_value:
+        if isinstance(expr[0], complex):
+            raise ValueError(f"Interval evaluation requires real values, got {expr}.")
+        return float(expr[0]), float(expr[0])
+
+    if expr.is_symbol and not expr.is_indexed:
+        if expr[0] in bounds:
+            lower, upper = bounds[expr[0]]
+            if lower > upper:
+                raise ValueError(f"The bounds of '{expr[0]}' are empty, {lower} > {upper}.")
+            return float(lower), float(upper)
+        if expr[0] in CONSTANTS and not isinstance(CONSTANTS[expr[0]], complex):
+            return float(CONSTANTS[expr[0]]), float(CONSTANTS[expr[0]])  # type: ignore
+        raise ValueError(f"The symbol '{expr[0]}' has no bounds.")
+
+    if expr.is_addition:
+        intervals = [evaluate_interval(arg, bounds) for arg in expr.args]
+        return sum(lower for lower, _ in intervals), sum(upper for _, upper in intervals)
+
+    if expr.is_multiplication:
+        result = (1.0, 1.0)
+        for arg in expr.args:
+            result = _multiply(result, evaluate_interval(arg, bounds))
+        return result
+
+    if expr.is_power:
+        return _power(expr, bounds)
+
+    if expr.is_function and len(expr.args) == 2:
+        return _function(expr[0][0], evaluate_interval(expr[1], bounds))
+
+    raise ValueError(f"The expression {expr} cannot be evaluated over intervals.")
+
+
+def _multiply(lhs: Interval, rhs: Interval) -> Interval:
+    # The product of zero and an infinite bound is zero, not `NaN`.
+    products = [0.0 if a == 0 or b == 0 else a * b for a in lhs for b in rhs]
+    return min(products), max(products)
+
+
+def _power(expr: Expression, bounds: dict[str, Interval]) -> Interval:
+    base, power = expr.args
+
+    # Powers of Euler's number and of other positive constants are monotonic.
+    if not power.is_value:
+        lower, upper = evaluate_interval(power, bounds)
+        if base.is_symbol and base[0] == "E":
+            return math.exp(lower), math.exp(upper)
+        if base.is_value and not isinstance(base[0], complex) and base[0] > 0:
+            values = sorted((base[0] ** lower, base[0] ** upper))
+            return values[0], values[1]
+        raise ValueError(f"The expression {expr} cannot be evaluated over intervals.")
+
+    if isinstance(power[0], complex):
+        raise ValueError(f"Interval evaluation requires real values, got {power}.")
+
+    lower, upper = evaluate_interval(base, bounds)
+    exponent = power[0]
+
+    if exponent < 0:
+        if lower <= 0 <= upper:
+            raise ValueError(f"The base of {expr} can be zero.")
+        lower, upper = 1 / upper, 1 / lower
+        exponent = -exponent
+
+    if float(exponent).is_integer():
+        n = int(exponent)
+        if n == 0:
+            return 1.0, 1.0
+        if n % 2 == 1 or lower >= 0:
+            return lower**n, upper**n
+        if upper <= 0:
+            return upper**n, lower**n
+        return 0.0, max(lower**n, upper**n)
+
+    if lower < 0:
+        raise ValueError(f"The base of {expr} can be negative.")
+    return lower**exponent, upper**exponent
+
+
+def _function(name: str, interval: Interval) -> Interval:
+    lower, upper = interval
+
+    if name in POSITIVE_DOMAIN and lower <= 0:
+        raise ValueError(f"The interval [{lower}, {upper}] exceeds the domain of {name}.")
+
+    if name in INCREASING_FUNCTIONS:
+        fn = INCREASING_FUNCTIONS[name]
+        return fn(lower), fn(upper)
+
+    if name == "abs" or name == "cosh":
+        fn = abs if name == "abs" else math.cosh
+        if lower <= 0 <= upper:
+            return fn(0.0), max(fn(lower), fn(upper))
+        values = sorted((fn(lower), fn(upper)))
+        return values[0], values[1]
+
+    if name == "sin":
+        return _sine(lower, upper)
+
+    if name == "cos":
+        return _sine(lower + math.pi / 2, upper + math.pi / 2)
+
+    raise ValueError(f"The function {name} cannot be evaluated over intervals.")
+
+
+def _sine(lower: float, upper: float) -> Interval:
+    if upper - lower >= 2 * math.pi:
+        return -1.0, 1.0
+
+    values = [math.sin(lower), math.sin(upper)]
+
+    # The extrema inside the interval, at `pi/2 + 2 pi k` and `-pi/2 + 2 pi k`.
+    def contains(phase: float) -> bool:
+        return math.ceil((lower - phase) / (2 * math.pi)) <= (upper - phase) / (2 * math.pi)
+
+    upper_bound = 1.0 if contains(math.pi / 2) else max(values)
+    lower_bound = -1.0 if contains(-math.pi / 2) else min(values)
+    return lower_bound, upper_bound
@@ -0,0 +1,109 @@
+from __future__ import annotations
+
+from functools import reduce
+from math import sqrt
+
+import numpy as np
+
+from .core.expression import Expression
+
+# Matrices of the known single-qubit operators in the computational basis `|0⟩, |1⟩`.
+SINGLE_QUBIT_MATRICES: dict[str, list[list[complex]]] = {
+    "I": [[1, 0], [0, 1]],
+    "X": [[0, 1], [1, 0]],
+    "Y": [[0, -1j], [1j, 0]],
+    "Z": [[1, 0], [0, -1]],
+    "H": [[1 / sqrt(2), 1 / sqrt(2)], [1 / sqrt(2), -1 / sqrt(2)]],
+    "NOT": [[0, 1], [1, 0]],
+    "Z{0}": [[1, 0], [0, 0]],
+    "Z{1}": [[0, 0], [0, 1]],
+    "X{+}": [[0.5, 0.5], [0.5, 0.5]],
+    "X{-}": [[0.5, -0.5], [-0.5, 0.5]],
+}
+
+
+def to_matrix(expr: Expression, num_qubits: int) -> np.ndarray:
+    """Returns the dense matrix of an operator expression acting on `num_qubits` qubits.
+
+    The expression must combine the known single-qubit operators, see `SINGLE_QUBIT_MATRICES`,
+    with numerical coefficients through sums, products, and non-negative integer powers.
+    Operators with multiple targets are applied to each of them, and controlled operators are
+    applied when all the controls are in the state `|1⟩`. The qubit `0` is the leftmost factor of
+    the tensor product, i.e., the most significant bit of the basis index.
+
+    Only meant for small systems, the size of the matrix grows as `4^num_qubits`.
+
+    Example:
+    ```
+    >>> to_matrix(X(0), 1)
+    array([[0.+0.j, 1.+0.j],
+           [1.+0.j, 0.+0.j]])
+    >>> to_matrix(X(0) * X(1), 2)[0, 3]
+    (1+0j)
+    ```
+
+    Raises:
+        ValueError: If the expression contains symbols, classical functions, unknown operators, or
+            operators acting outside the `num_qubits` qubits.
+    """
+
+    identity = np.eye(2**num_qubits, dtype=complex)
+
+    if expr.is_value:
+        return expr[0] * identity
+
+    if expr.is_quantum_operator:
+        return _operator_matrix(expr, num_qubits)
+
+    if expr.is_addition:
+        return reduce(np.add, (to_matrix(arg, num_qubits) for arg in expr.args))
+
+    if expr.is_multiplication or expr.is_kronecker_product:
+        return reduce(np.matmul, (to_matrix(arg, num_qubits) for arg in expr.args))
+
+    if (
+        expr.is_power
+        and expr[1].is_value
+        and not isinstance(expr[1][0], complex)
+        and float(expr[1][0]).is_integer()
+        and expr[1][0] >= 0
+    ):
+        return np.linalg.matrix_power(to_matrix(expr[0], num_qubits), int(expr[1][0]))
+
+    raise ValueError(
+        f"Cannot build the matrix of {expr}, only numerical values and known operators are "
+        "supported. Evaluate the symbols first."
+    )
+
+
+def _operator_matrix(expr: Expression, num_qubits: int) -> np.ndarray:
+    name = expr[0][0] if expr[0].is_symbol else None
+
+    if name not in SINGLE_QUBIT_MATRICES:
+        raise ValueError(f"The operator {expr[0]} has no known matrix.")
+
+    support = expr[1]
+    if support.max_index >= num_qubits:
+        raise ValueError(f"The operator {expr} acts outside the {num_qubits} qubits.")
+
+    matrix = np.array(SINGLE_QUBIT_MATRICES[name], dtype=complex)
+    if expr.get("is_dagger"):
+        matrix = matrix.conj().T
+
+    # An empty target means the operator is applied to all the qubits.
+    targets = support.target or tuple(range(num_qubits))
+    operator = reduce(np.matmul, (_embed(matrix, i, num_qubits) for i in targets))
+
+    if not support.control:
+        return operator
+
+    # The controlled operator is `I + P (U - I)`, where `P` projects the controls onto `|1⟩`.
+    one = np.array(SINGLE_QUBIT_MATRICES["Z{1}"], dtype=complex)
+    projector = reduce(np.matmul, (_embed(one, j, num_qubits) for j in support.control))
+    identity = np.eye(2**num_qubits, dtype=complex)
+    return identity + projector @ (operator - identity)
+
+
+def _embed(matrix: np.ndarray, qubit: int, num_qubits: int) -> np.ndarray:
+    factors = [matrix if i == qubit else np.eye(2, dtype=complex) for i in range(num_qubits)]
+    return reduce(np.kron, factors)
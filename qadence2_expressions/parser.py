@@ -0,0 +1,304 @@
+from __future__ import annotations
+
+import re
+from typing import Callable
+
+from .core.constructors import function, symbol, value
+from .core.expression import Expression
+from .core.utils import DepthExceededError
+from .functions import cos, exp, log, sin, sqrt
+
+# Functions with a dedicated constructor. Any other function name is parsed as a generic
+# symbolic function.
+KNOWN_FUNCTIONS: dict[str, Callable[[Expression], Expression]] = {
+    "cos": cos,
+    "exp": exp,
+    "log": log,
+    "sin": sin,
+    "sqrt": sqrt,
+}
+
+# Maximum nesting of parentheses, signs, powers, and calls accepted by default. Each level uses a
+# few stack frames of the recursive descent, so it must stay well below the recursion limit.
+MAX_DEPTH = 100
+
+TOKEN_PATTERN = re.compile(
+    r"\s*(?:"
+    r"(?P<number>(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)"
+    r"|(?P<name>[A-Za-z_]\w*)"
+    r"|(?P<operator>\*\*|//|[-+*/%^(),=])"
+    r")"
+)
+
+
+def parse(source: str, max_depth: int = MAX_DEPTH) -> Expression:
+    """Parse a string into an expression.
+
+    The syntax is compatible with the output of SymPy's `str` for the supported subset of
+    operations. Both `**` and `^` are accepted as power, `I` is the imaginary unit, and `E` is
+    the Euler's number. The floor division `//` and the remainder `%`, also written `Mod(x, y)`,
+    have the precedence of the multiplication. Numbers can be written in scientific notation,
+    e.g., `1.5e-9`. Multiplication must always be explicit.
+
+    Example:
+    ```
+    >>> parse("x**2 + 3*I")
+    3j + x ^ 2.0
+    >>> parse("2 * sin(x) / y")
+    2.0 * sin(x) * y ^ -1.0
+    ```
+
+    Args:
+        source: The string representation of the expression.
+        max_depth: The maximum nesting of parentheses, signs, powers, and function calls.
+
+    Returns:
+        The parsed expression.
+
+    Raises:
+        ParseError: If the string is not a valid expression. The error is a `SyntaxError` holding
+            the location of the problem. When the input is nested deeper than `max_depth`, the
+            error is also a `DepthExceededError`.
+    """
+
+    return Parser(source, max_depth).parse()
+
+
+class ParseError(SyntaxError):
+    """The error raised when parsing an invalid expression.
+
+    The `start` and `end` attributes delimit the offending part of the `source` string. The error
+    message points at it like a compiler diagnostic.
+
+    Example:
+    ```
+    >>> parse("2 * (x + 1")
+    ParseError: Invalid expression at position 4: unclosed parenthesis.
+        2 * (x + 1
+            ^
+    ```
+    """
+
+    def __init__(self, message: str, source: str, start: int, end: int) -> None:
+        super().__init__(message)
+        self.message = message
+        self.source = source
+        self.start = start
+        self.end = end
+
+    def __str__(self) -> str:
+        caret = " " * self.start + "^" * max(self.end - self.start, 1)
+        return (
+            f"Invalid expression at position {self.start}: {self.message}.\n"
+            f"    {self.source}\n"
+            f"    {caret}"
+        )
+
+
+class ParseDepthError(ParseError, DepthExceededError):
+    """The error raised when the input is nested deeper than the parser's `max_depth`."""
+
+
+class Parser:
+    """A recursive descent parser following the grammar below.
+
+        expr  := term (('+' | '-') term)*
+        term  := unary (('*' | '/' | '//' | '%') unary)*
+        unary := ('+' | '-') unary | power
+        power := atom (('**' | '^') unary)?
+        atom  := number | name | name '(' arg (',' arg)* ')' | '(' expr ')'
+        arg   := (name '=')? expr
+    """
+
+    def __init__(self, source: str, max_depth: int = MAX_DEPTH) -> None:
+        self.source = source
+        self.tokens = tokenize(source)
+        self.position = 0
+        self.max_depth = max_depth
+        self.depth = 0
+
+    def parse(self) -> Expression:
+        expr = self.expr()
+
+        if self.peek() is not None:
+            self.error(f"unexpected token '{self.peek()}'")
+
+        return expr
+
+    def peek(self) -> str | None:
+        if self.position < len(self.tokens):
+            return self.tokens[self.position][1]
+        return None
+
+    def advance(self) -> str:
+        token = self.peek()
+        if token is None:
+            self.error("unexpected end of input")
+        self.position += 1
+        return token  # type: ignore
+
+    def close(self, opening: int) -> None:
+        """Consume the parenthesis closing the one in the token at `opening`."""
+
+        if self.peek() is None:
+            start = self.tokens[opening][0]
+            raise ParseError("unclosed parenthesis", self.source, start, start + 1)
+
+        if self.peek() != ")":
+            self.error("expected ')'")
+
+        self.position += 1
+
+    def error(self, message: str) -> None:
+        """Raise a `ParseError` pointing at the current token, or at the end of the input."""
+
+        if self.position < len(self.tokens):
+            start, token = self.tokens[self.position]
+            end = start + len(token)
+        else:
+            start = end = len(self.source.rstrip())
+
+        raise ParseError(message, self.source, start, end)
+
+    def expr(self) -> Expression:
+        result = self.term()
+        while self.peek() in ("+", "-"):
+            if self.advance() == "+":
+                result = result + self.term()
+            else:
+                result = result - self.term()
+        return result
+
+    def term(self) -> Expression:
+        result = self.unary()
+        while self.peek() in ("*", "/", "//", "%"):
+            operator = self.advance()
+            if operator == "*":
+                result = result * self.unary()
+            elif operator == "/":
+                result = result / self.unary()
+            elif operator == "//":
+                result = result // self.unary()
+            else:
+                result = result % self.unary()
+        return result
+
+    def unary(self) -> Expression:
+        # Every nested construction goes through here, so it's the place to limit the depth.
+        self.depth += 1
+        if self.depth > self.max_depth:
+            start, token = self.tokens[self.position]
+            message = f"maximum depth of {self.max_depth} exceeded"
+            raise ParseDepthError(message, self.source, start, start + len(token))
+
+        if self.peek() == "-":
+            self.advance()
+            result = -self.unary()
+        elif self.peek() == "+":
+            self.advance()
+            result = self.unary()
+        else:
+            result = self.power()
+
+        self.depth -= 1
+        return result
+
+    def power(self) -> Expression:
+        base = self.atom()
+        if self.peek() in ("**", "^"):
+            self.advance()
+            return base ** self.unary()  # type: ignore
+        return base
+
+    def atom(self) -> Expression:
+        token = self.peek()
+
+        if token == "(":
+            opening = self.position
+            self.advance()
+            result = self.expr()
+            self.close(opening)
+            return result
+
+        if token is None:
+            self.error("unexpected end of input")
+
+        if not (token[0].isalnum() or token[0] in "_."):  # type: ignore
+            self.error("expected a number, a symbol or a function")
+
+        token = self.advance()
+
+        if token[0].isdigit() or token[0] == ".":
+            return value(float(token))
+
+        if self.peek() == "(":
+            return self.call(token)
+
+        if token == "I":
+            return value(1j)
+
+        if token == "E":
+            return Expression.symbol("E")
+
+        return symbol(token)
+
+    def call(self, name: str) -> Expression:
+        opening = self.position
+        self.advance()
+        args: list[Expression] = []
+        kwargs: dict[str, Expression] = dict()
+
+        # Functions without arguments, like `f()`.
+        if self.peek() == ")":
+            self.close(opening)
+            return function(name)
+
+        while True:
+            keyword = self.peek()
+            has_keyword = (
+                self.position + 1 < len(self.tokens)
+                and self.tokens[self.position + 1][1] == "="
+                and keyword.isidentifier()  # type: ignore [union-attr]
+            )
+
+            if has_keyword:
+                self.position += 2
+                kwargs[keyword] = self.expr()  # type: ignore [index]
+            elif kwargs:
+                self.error("positional argument follows named arguments")
+            else:
+                args.append(self.expr())
+
+            if self.peek() != ",":
+                break
+            self.advance()
+
+        self.close(opening)
+
+        if name in KNOWN_FUNCTIONS and len(args) == 1 and not kwargs:
+            return KNOWN_FUNCTIONS[name](args[0])
+
+        # SymPy writes the remainder as a function.
+        if name == "Mod" and len(args) == 2 and not kwargs:
+            return args[0] % args[1]
+
+        return function(name, *args, **kwargs)
+
+
+def tokenize(source: str) -> list[tuple[int, str]]:
+    """Split the `source` into a list of `(position, token)` pairs."""
+
+    tokens = []
+    position = 0
+    source = source.rstrip()
+
+    while position < len(source):
+        match = TOKEN_PATTERN.match(source, position)
+        if not match:
+            offset = len(source) - len(source[position:].lstrip())
+            raise ParseError(f"unexpected character '{source[offset]}'", source, offset, offset + 1)
+
+        tokens.append((match.start(match.lastgroup), match.group(match.lastgroup)))  # type: ignore
+        position = match.end()
+
+    return tokens
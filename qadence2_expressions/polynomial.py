@@ -0,0 +1,511 @@
+from __future__ import annotations
+
+from collections import Counter
+
+from .core.expression import Expression, noncommutative_multiplication
+from .core.utils import Numeric
+from .evaluation import evaluate, parameters
+from .replace import evaluate_node, prod
+from .simplify import rewrite_bottom_up
+
+
+def degree(expr: Expression, var: str) -> int | None:
+    """Returns the degree of the expression as a polynomial in the symbol named `var`.
+
+    Example:
+    ```
+    >>> degree(x**2 * y + x, "x")
+    2
+    >>> degree(sin(x), "x")
+    None
+    ```
+
+    Args:
+        expr: The expression to inspect.
+        var: The name of the symbol.
+
+    Returns:
+        The highest power of `var` in the expression, or `None` if the expression is not a
+        polynomial in `var`.
+    """
+
+    if expr.is_value:
+        return 0
+
+    if expr.is_symbol:
+        return 1 if expr[0] == var else 0
+
+    if expr.is_addition:
+        degrees = [degree(arg, var) for arg in expr.args]
+        return None if None in degrees else max(degrees)  # type: ignore
+
+    if expr.is_multiplication:
+        degrees = [degree(arg, var) for arg in expr.args]
+        return None if None in degrees else sum(degrees)  # type: ignore
+
+    if expr.is_power:
+        base_degree = degree(expr[0], var)
+        power_degree = degree(expr[1], var)
+
+        if base_degree == 0 and power_degree == 0:
+            return 0
+
+        # Only non-negative integer powers keep the polynomial structure.
+        power = expr[1]
+        if (
+            base_degree is None
+            or power_degree != 0
+            or not power.is_value
+            or isinstance(power[0], complex)
+            or power[0] < 0
+            or not float(power[0]).is_integer()
+        ):
+            return None
+
+        return base_degree * int(power[0])
+
+    # Functions and quantum operators are polynomials only when independent of `var`.
+    args = expr[1:] if expr.is_function else expr[:1] if expr.is_quantum_operator else expr.args
+    if all(degree(arg, var) == 0 for arg in args):
+        return 0
+
+    return None
+
+
+def total_degree(expr: Expression) -> int | None:
+    """Returns the total degree of the expression as a polynomial in all of its symbols, that is,
+    the highest sum of the exponents of the symbols in a single monomial.
+
+    Example:
+    ```
+    >>> total_degree(x**2 * y + x)
+    3
+    >>> total_degree(x * cos(y))
+    None
+    ```
+
+    Returns:
+        The total degree, or `None` if the expression is not a polynomial in its symbols.
+    """
+
+    if expr.is_value:
+        return 0
+
+    if expr.is_symbol:
+        return 1
+
+    if expr.is_addition:
+        degrees = [total_degree(arg) for arg in expr.args]
+        return None if None in degrees else max(degrees)  # type: ignore
+
+    if expr.is_multiplication:
+        degrees = [total_degree(arg) for arg in expr.args]
+        return None if None in degrees else sum(degrees)  # type: ignore
+
+    if expr.is_power:
+        base_degree = total_degree(expr[0])
+        power = expr[1]
+
+        if (
+            base_degree is None
+            or not power.is_value
+            or isinstance(power[0], complex)
+            or power[0] < 0
+            or not float(power[0]).is_integer()
+        ):
+            return None
+
+        return base_degree * int(power[0])
+
+    # Functions and quantum operators are constant coefficients only when they have no symbols.
+    return 0 if not expr.free_symbols else None
+
+
+def to_sop(expr: Expression) -> Expression:
+    """Expand an expression into a flat sum of products.
+
+    Products of sums and positive integer powers of sums are distributed, recursively, so no term
+    of the result contains a sum. Function arguments and the bases of negative or non-integer
+    powers are irreducible, and are kept as they are.
+
+    Example:
+    ```
+    >>> to_sop((a + b) * (c + d))
+    a * c + a * d + b * c + b * d
+    >>> to_sop((a + 1) ** 2 * sin(a + b))
+    sin(a + b) + 2.0 * a * sin(a + b) + a ^ 2.0 * sin(a + b)
+    ```
+    """
+
+    if expr.is_addition:
+        return sum((to_sop(arg) for arg in expr.args), Expression.zero())
+
+    if expr.is_multiplication and not expr.is_commutative:
+        with noncommutative_multiplication():
+            return prod(to_sop(arg) for arg in expr.args)
+
+    if expr.is_multiplication or expr.is_kronecker_product:
+        return prod(to_sop(arg) for arg in expr.args)
+
+    if expr.is_power:
+        base, power = expr.args
+        if (
+            power.is_value
+            and not isinstance(power[0], complex)
+            and power[0] > 0
+            and float(power[0]).is_integer()
+        ):
+            base = to_sop(base)
+            if base.is_addition:
+                return prod(base for _ in range(int(power[0])))
+
+    # Powers of operator sums are wrapped as quantum operators. Once expanded, the terms are
+    # operators themselves and the wrapper is dropped.
+    if expr.is_quantum_operator and not (expr[0].is_symbol or expr[0].is_function):
+        inner = to_sop(expr[0])
+        if inner.is_addition:
+            return inner
+        return Expression.quantum_operator(inner, expr[1], **expr.attrs)
+
+    return expr
+
+
+def is_identically_zero(expr: Expression) -> bool:
+    """Check whether an expression is zero for any value of its symbols, e.g., `x - x`.
+
+    The expression is re-evaluated and expanded with `to_sop`, including the arguments of the
+    functions, and compared with zero. This is a best-effort check limited to the algebraic rules
+    implemented by the evaluation; identities like `sin(x)^2 + cos(x)^2 = 1` are not recognised,
+    so a `False` result doesn't prove the expression is non-zero.
+
+    Example:
+    ```
+    >>> is_identically_zero(Expression.add(x, Expression.mul(value(-1), x)))
+    True
+    >>> is_identically_zero((x + 1) ** 2 - x**2 - 2 * x - 1)
+    True
+    ```
+    """
+
+    return rewrite_bottom_up(expr, lambda node: to_sop(evaluate_node(node))).is_zero
+
+
+def is_identically_one(expr: Expression) -> bool:
+    """Check whether an expression is one for any value of its symbols, with the same limitations
+    as `is_identically_zero`.
+    """
+
+    return is_identically_zero(expr - 1)
+
+
+def factor_common(expr: Expression) -> Expression:
+    """Pull the factors common to every term of a sum out front, the inverse of `to_sop`.
+
+    Factors are compared as a whole, so `x` is not extracted from `x^2`. Numerical coefficients are
+    kept inside the sum. The result is built without evaluation,
+    otherwise the product would be distributed back over the sum. Expressions other than sums,
+    and sums without common factors, are returned as they are.
+
+    Example:
+    ```
+    >>> factor_common(x * a + x * b)
+    x * (a + b)
+    >>> factor_common(2 * x * y + x**2 * y)
+    y * (2.0 * x + x ^ 2.0)
+    ```
+    """
+
+    if not expr.is_addition:
+        return expr
+
+    def factors(term: Expression) -> tuple[Expression, ...]:
+        return term.args if term.is_multiplication and term.is_commutative else (term,)
+
+    common: Counter[Expression] | None = None
+    for term in expr.args:
+        counts = Counter(factor for factor in factors(term) if not factor.is_value)
+        common = counts if common is None else common & counts
+
+    if not common:
+        return expr
+
+    quotients = []
+    for term in expr.args:
+        remaining = Counter(common)
+        kept = []
+        for factor in factors(term):
+            if remaining[factor] > 0:
+                remaining[factor] -= 1
+            else:
+                kept.append(factor)
+        quotients.append(prod(kept))
+
+    return Expression.mul(*common.elements(), sum(quotients, Expression.zero()))
+
+
+def order_terms_by_degree(expr: Expression, var: str) -> Expression:
+    """Reorder the terms of a sum by descending degree in the symbol named `var`.
+
+    Terms with the same degree are ordered by `Expression.sort_key`. Terms that are not
+    polynomial in `var` are placed last. The result is equal to the original expression, only
+    its visualisation changes.
+
+    Example:
+    ```
+    >>> order_terms_by_degree(1 + x**2 + x, "x")
+    x ^ 2.0 + x + 1.0
+    ```
+    """
+
+    if not expr.is_addition:
+        return expr
+
+    def key(term: Expression) -> tuple:
+        term_degree = degree(term, var)
+        return (term_degree is None, -(term_degree or 0), term.sort_key())
+
+    return Expression.add(*sorted(expr.args, key=key))
+
+
+def to_horner(expr: Expression, var: str) -> Expression:
+    """Rewrite a polynomial in the symbol named `var` into the Horner form.
+
+    The coefficients can depend on other symbols. The result is built without evaluation to
+    preserve its nested structure, which requires fewer operations to be evaluated.
+
+    Example:
+    ```
+    >>> to_horner(a * x**3 + b * x**2 + c * x + d, "x")
+    ((a * x + b) * x + c) * x + d
+    ```
+
+    Raises:
+        ValueError: If the expression is not an expanded polynomial in `var`.
+    """
+
+    coefficients, symbol = split_powers(expr, var)
+    if symbol is None:
+        return expr
+
+    max_power = max(coefficients)
+    result = coefficients[max_power]
+
+    for power in range(max_power - 1, -1, -1):
+        result = symbol if result.is_one else Expression.mul(result, symbol)
+        coefficient = coefficients.get(power, Expression.zero())
+        if not coefficient.is_zero:
+            result = Expression.add(result, coefficient)
+
+    return result
+
+
+def coefficients(expr: Expression, var: str) -> list[Numeric]:
+    """Returns the numerical coefficients of a polynomial in the symbol named `var`, indexed by
+    the power of `var`.
+
+    Example:
+    ```
+    >>> coefficients(3 * x**2 + 5, "x")
+    [5.0, 0.0, 3.0]
+    ```
+
+    Raises:
+        ValueError: If the expression is not an expanded polynomial in `var`, or if any of the
+            coefficients is not a constant.
+    """
+
+    powers, _ = split_powers(expr, var)
+    result: list[Numeric] = [0.0] * (max(powers) + 1)
+
+    for power, coefficient in powers.items():
+        if parameters(coefficient) or coefficient.subspace is not None:
+            raise ValueError(f"The coefficient of {var}^{power}, {coefficient}, is not constant.")
+        result[power] = evaluate(coefficient)
+
+    return result
+
+
+def as_affine(expr: Expression, var: str) -> tuple[Expression, Expression] | None:
+    """Split an expression that is linear in the symbol named `var` into its slope and intercept,
+    `expr = slope * var + intercept`.
+
+    The expression is expanded with `to_sop` first. Unlike `coefficients`, the slope and the
+    intercept can depend on other symbols.
+
+    Example:
+    ```
+    >>> as_affine(3 * x + 2 * y, "x")
+    (3.0, 2.0 * y)
+    >>> as_affine((x + y) * (x - y), "x")
+    None
+    ```
+
+    Returns:
+        The slope and the intercept, or `None` if the expression is not linear in `var`.
+    """
+
+    try:
+        powers, _ = split_powers(to_sop(expr), var)
+    except ValueError:
+        return None
+
+    if any(power > 1 for power in powers):
+        return None
+
+    return powers.get(1, Expression.zero()), powers.get(0, Expression.zero())
+
+
+def to_quadratic_coefficients(
+    expr: Expression, variables: list[str]
+) -> tuple[dict[tuple[int, int], Numeric], dict[int, Numeric], Numeric]:
+    """Extract the coefficients of a quadratic function of the `variables`, as in QUBO and Ising
+    problems.
+
+    The expression is expanded with `to_sop`. The variables are referred to by their position in
+    `variables`, and the pairs are ordered so that `i <= j`. Squares are kept as the diagonal
+    pairs `(i, i)`, since their reduction depends on the encoding, `x^2 = x` for binary
+    variables and `s^2 = 1` for spins.
+
+    Example:
+    ```
+    >>> to_quadratic_coefficients(2 * a * b + 3 * a + 1, ["a", "b"])
+    ({(0, 1): 2.0}, {0: 3.0}, 1.0)
+    ```
+
+    Returns:
+        The coefficients of the pairs of variables, of the single variables, and the constant.
+        Zero coefficients are omitted.
+
+    Raises:
+        ValueError: If the expression is not a polynomial of degree at most two in the
+            `variables`, or if it depends on other symbols.
+    """
+
+    slots = {name: i for i, name in enumerate(variables)}
+    quadratic: dict[tuple[int, int], Numeric] = dict()
+    linear: dict[int, Numeric] = dict()
+    constant: Numeric = 0.0
+
+    expanded = to_sop(expr)
+
+    for term in expanded.args if expanded.is_addition else (expanded,):
+        coefficient: Numeric = 1.0
+        indices: list[int] = []
+
+        for factor in term.args if term.is_multiplication else (term,):
+            base, power = factor[:2] if factor.is_power else (factor, Expression.one())
+
+            if factor.is_value:
+                coefficient *= factor[0]
+            elif (
+                base.is_symbol
+                and not base.is_indexed
+                and base[0] in slots
+                and power.is_value
+                and power[0] in (1, 2)
+            ):
+                indices.extend([slots[base[0]]] * int(power[0].real))
+            else:
+                raise ValueError(f"The term {term} is not quadratic in the variables {variables}.")
+
+        if len(indices) > 2:
+            raise ValueError(f"The term {term} is not quadratic in the variables {variables}.")
+
+        if len(indices) == 2:
+            pair = (min(indices), max(indices))
+            quadratic[pair] = quadratic.get(pair, 0.0) + coefficient
+        elif len(indices) == 1:
+            linear[indices[0]] = linear.get(indices[0], 0.0) + coefficient
+        else:
+            constant += coefficient
+
+    quadratic = {pair: c for pair, c in quadratic.items() if c != 0}
+    linear = {i: c for i, c in linear.items() if c != 0}
+
+    return quadratic, linear, constant
+
+
+def split_powers(expr: Expression, var: str) -> tuple[dict[int, Expression], Expression | None]:
+    """Split an expanded polynomial in the symbol named `var` into its coefficients.
+
+    Returns:
+        The coefficients indexed by the power of `var`, and the symbol itself (`None` if the
+        expression doesn't depend on `var`).
+
+    Raises:
+        ValueError: If the expression is not an expanded polynomial in `var`.
+    """
+
+    coefficients: dict[int, Expression] = dict()
+    symbol = None
+
+    for term in expr.args if expr.is_addition else (expr,):
+        power = 0
+        coefficient = Expression.one()
+
+        for factor in term.args if term.is_multiplication else (term,):
+            base = factor[0] if factor.is_power else factor
+            factor_degree = degree(factor, var)
+
+            if factor_degree == 0:
+                coefficient = coefficient * factor
+            elif factor_degree is not None and base.is_symbol and base[0] == var:
+                power += factor_degree
+                symbol = base
+            else:
+                raise ValueError(f"{expr} is not an expanded polynomial in '{var}'.")
+
+        coefficients[power] = coefficients.get(power, Expression.zero()) + coefficient
+
+    return coefficients, symbol
+
+
+def monomials(expr: Expression, variables: list[str]) -> dict[tuple[int, ...], Numeric]:
+    """Returns the coefficients of a polynomial in the `variables`, indexed by the exponents of
+    its monomials.
+
+    The expression is expanded with `to_sop`, and the exponents are aligned with `variables`.
+    Zero coefficients are omitted.
+
+    Example:
+    ```
+    >>> monomials(2 * x**2 * y + 3 * x, ["x", "y"])
+    {(2, 1): 2.0, (1, 0): 3.0}
+    ```
+
+    Raises:
+        ValueError: If the expression is not a polynomial in the `variables`, or if it depends on
+            other symbols.
+    """
+
+    slots = {name: i for i, name in enumerate(variables)}
+    result: dict[tuple[int, ...], Numeric] = dict()
+
+    expanded = to_sop(expr)
+
+    for term in expanded.args if expanded.is_addition else (expanded,):
+        coefficient: Numeric = 1.0
+        exponents = [0] * len(variables)
+
+        for factor in term.args if term.is_multiplication else (term,):
+            base, power = factor[:2] if factor.is_power else (factor, Expression.one())
+
+            if factor.is_value:
+                coefficient *= factor[0]
+            elif (
+                base.is_symbol
+                and not base.is_indexed
+                and base[0] in slots
+                and power.is_value
+                and not isinstance(power[0], complex)
+                and float(power[0]).is_integer()
+                and power[0] > 0
+            ):
+                exponents[slots[base[0]]] += int(power[0])
+            else:
+                raise ValueError(f"The term {term} is not a monomial in the variables {variables}.")
+
+        key = tuple(exponents)
+        result[key] = result.get(key, 0.0) + coefficient
+
+    return {key: c for key, c in result.items() if c != 0}
@@ -0,0 +1,97 @@
+from __future__ import annotations
+
+from .core.expression import Expression
+from .core.utils import Numeric
+
+
+def to_sympy_string(expr: Expression) -> str:
+    """Stringify an expression using SymPy's syntax.
+
+    The result can be parsed back with `parse` or pasted into SymPy's `sympify`.
+
+    Example:
+    ```
+    >>> to_sympy_string(x**2 + 3j)
+    '3*I + x**2'
+    ```
+
+    Raises:
+        ValueError: If the expression contains quantum operators.
+    """
+
+    if expr.is_value:
+        return sympy_number(expr[0])
+
+    if expr.is_symbol:
+        return str(expr[0])
+
+    if expr.is_function and expr[0][0] == "mod" and len(expr.args) == 3:
+        return f"Mod({to_sympy_string(expr[1])}, {to_sympy_string(expr[2])})"
+
+    if expr.is_function and expr[0][0] == "floordiv" and len(expr.args) == 3:
+        return f"floor({to_sympy_string(expr[1] / expr[2])})"
+
+    if expr.is_function:
+        args = list(map(to_sympy_string, expr[1:]))
+        keywords = expr.get("keywords", ())
+        if keywords:
+            positional = len(args) - len(keywords)
+            args[positional:] = [f"{k}={v}" for k, v in zip(keywords, args[positional:])]
+        return f"{expr[0][0]}({', '.join(args)})"
+
+    if expr.is_addition:
+        result = to_sympy_string(expr[0])
+        for term in expr[1:]:
+            term_str = to_sympy_string(term)
+            result += f" - {term_str[1:]}" if term_str.startswith("-") else f" + {term_str}"
+        return result
+
+    if expr.is_multiplication:
+        factors = list(expr.args)
+        sign = ""
+        if factors[0].is_value and factors[0][0] == -1:
+            sign = "-"
+            factors = factors[1:]
+        return sign + "*".join(sympy_with_brackets(arg, expr) for arg in factors)
+
+    if expr.is_power:
+        base, power = expr.args
+        if power.is_value and power[0] == 0.5:
+            return f"sqrt({to_sympy_string(base)})"
+        return f"{sympy_with_brackets(base, expr)}**{sympy_with_brackets(power, expr)}"
+
+    raise ValueError(f"The expression {expr} has no SymPy representation.")
+
+
+def sympy_number(x: Numeric) -> str:
+    """Stringify numerical values using the imaginary unit `I` and dropping trailing `.0`."""
+
+    def real(r: float | int) -> str:
+        return str(int(r)) if float(r).is_integer() else repr(float(r))
+
+    if not isinstance(x, complex):
+        return real(x)
+
+    if x.real == 0:
+        return "I" if x.imag == 1 else "-I" if x.imag == -1 else f"{real(x.imag)}*I"
+
+    sign = "-" if x.imag < 0 else "+"
+    return f"({real(x.real)} {sign} {real(abs(x.imag))}*I)"
+
+
+def sympy_with_brackets(expr: Expression, parent: Expression) -> str:
+    """Wrap the operand of `parent` in brackets when required by the operator precedence."""
+
+    result = to_sympy_string(expr)
+
+    needs_brackets = expr.is_addition or (
+        parent.is_power
+        and (
+            expr.is_multiplication
+            or expr.is_power
+            or result.startswith("-")
+            or (expr.is_value and "I" in result and not result.startswith("("))
+        )
+    )
+
+    return f"({result})" if needs_brackets else result
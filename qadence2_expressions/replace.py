@@ -1,8 +1,12 @@
 from __future__ import annotations
 
-from typing import Iterable
+from typing import Callable, Iterable
 
-from .core.expression import Expression
+from .core.constructors import promote
+from .core.environment import Environment
+from .core.expression import Expression, noncommutative_multiplication
+from .core.utils import Numeric
+from .simplify import rewrite_bottom_up
 
 
 def prod(exprs: Iterable[Expression]) -> Expression:
@@ -14,7 +18,8 @@ def prod(exprs: Iterable[Expression]) -> Expression:
 
 def evaluate(expr: Expression) -> Expression:
     if expr.is_multiplication or expr.is_kronecker_product:
-        return prod(evaluate(arg) for arg in expr.args)
+        args = tuple(evaluate(arg) for arg in expr.args)
+        return evaluate_node(Expression(expr.head, *args, **expr.attrs))
 
     if expr.is_addition:
         return sum(evaluate(arg) for arg in expr.args)  # type: ignore
@@ -28,26 +33,253 @@ def evaluate(expr: Expression) -> Expression:
     return expr
 
 
+def evaluate_node(expr: Expression) -> Expression:
+    """Evaluate the operation of `expr` assuming its arguments are already evaluated."""
+
+    if expr.is_multiplication and not expr.is_commutative:
+        with noncommutative_multiplication():
+            return prod(expr.args)
+
+    if expr.is_multiplication or expr.is_kronecker_product:
+        return prod(expr.args)
+
+    if expr.is_addition:
+        return sum(expr.args)  # type: ignore
+
+    if expr.is_power:
+        return expr[0] ** expr[1]  # type: ignore
+
+    return expr
+
+
 def replace(expr: Expression, rules: dict[Expression, Expression]) -> Expression:
-    return evaluate(replace_core(expr, rules))
+    """Replace the subexpressions of `expr` according to the `rules` and evaluate the result.
+
+    All the rules are applied simultaneously in a single traversal, so the outcome of a rule is
+    never rewritten by another one. This allows, for instance, to swap two symbols.
+
+    Only the subexpressions affected by the rules are rebuilt and evaluated; the unchanged ones
+    are reused as they are, sharing the same objects with the original expression.
+
+    Example:
+    ```
+    >>> replace(x + 2 * y, {x: y, y: x})
+    y + 2.0 * x
+    ```
+    """
+    return replace_core(expr, rules)
+
+
+def substitute_many_exprs(
+    expr: Expression, rules: Iterable[tuple[Expression, Expression]]
+) -> Expression:
+    """Replace whole subexpressions of `expr` according to a sequence of `(old, new)` rules.
+
+    Like `replace`, the rules are applied simultaneously in a single traversal, so the new
+    subexpressions are never rewritten by the other rules. When several rules have the same `old`
+    subexpression, the first one wins. The subexpressions are matched as a whole, so the rule
+    `x + 1 -> a` doesn't apply to the terms of the sum `x + y + 1`.
+
+    Example:
+    ```
+    >>> substitute_many_exprs(sin(x + 1) * y, [(x + 1, a), (y, b), (a, y)])
+    sin(a) * b
+    ```
+    """
+
+    table: dict[Expression, Expression] = {}
+    for old, new in rules:
+        table.setdefault(old, new)
+
+    return replace_core(expr, table)
+
+
+def replace_symbols(expr: Expression, fn: Callable[[str], Expression | None]) -> Expression:
+    """Replace the symbols of `expr` by the expressions returned by `fn` and evaluate the result.
+
+    The function `fn` is called with the name of each symbol and returns its replacement, or
+    `None` to keep the symbol as it is. The names of functions and quantum operators, as well as
+    the elements of array symbols, are not replaced.
+
+    Example:
+    ```
+    >>> rescale = lambda name: 2 * symbol(name) if name.startswith("theta") else None
+    >>> replace_symbols(theta_1 + theta_2 * x, rescale)
+    2.0 * theta_1 + 2.0 * theta_2 * x
+    ```
+    """
+
+    rules = dict()
+    for subexpr in expr.subexpressions():
+        if subexpr.is_symbol and not subexpr.is_indexed:
+            replacement = fn(subexpr[0])
+            if replacement is not None:
+                rules[subexpr] = replacement
+
+    return replace_core(expr, rules) if rules else expr
+
+
+def subs(expr: Expression, bindings: dict[str, Expression | Numeric]) -> Expression:
+    """Replace the symbols named in `bindings` and simplify the result in a single traversal.
+
+    Unlike `replace`, which only re-evaluates the operations affected by the replacements, every
+    operation is evaluated, so the constants produced by the substitution and the terms left
+    unevaluated in the original expression are folded at once. As in `replace_symbols`, the
+    names of functions and quantum operators, as well as the elements of array symbols, are not
+    replaced.
+
+    Example:
+    ```
+    >>> subs(Expression.add(x * y, Expression.mul(value(0), z)), {"x": 2})
+    2.0 * y
+    ```
+    """
+
+    rules = {name: promote(binding) for name, binding in bindings.items()}
+
+    def visit(expr: Expression) -> Expression:
+        if expr.is_indexed:
+            index = expr.get("index")
+            if not isinstance(index, Expression):
+                return expr
+            attrs = {k: v for k, v in expr.attrs.items() if k != "index"}
+            return Expression.indexed(expr[0], visit(index), **attrs)
+
+        if expr.is_symbol:
+            return rules.get(expr[0], expr)
+
+        if expr.is_value:
+            return expr
+
+        if expr.is_function:
+            # Raw numerical arguments, e.g., the angle of a parametric operator, are kept.
+            args = tuple(visit(arg) if isinstance(arg, Expression) else arg for arg in expr[1:])
+            return Expression(expr.head, expr[0], *args, **expr.attrs)
+
+        if expr.is_quantum_operator:
+            if expr[0].is_symbol:
+                return expr
+            return Expression.quantum_operator(visit(expr[0]), expr[1], **expr.attrs)
+
+        args = tuple(visit(arg) for arg in expr.args)
+        return evaluate_node(Expression(expr.head, *args, **expr.attrs))
+
+    return visit(expr)
+
+
+def reindex(expr: Expression, name: str, index_map: dict[int, int]) -> Expression:
+    """Change the indices of the elements of the array symbol `name` according to `index_map`.
+
+    Elements with indices not present in `index_map` or symbolic indices, as well as other
+    symbols, are left unchanged.
+
+    Example:
+    ```
+    >>> reindex(q[0] * q[1] + q[2], "q", {0: 5, 1: 3})
+    q[5] * q[3] + q[2]
+    ```
+    """
+
+    rules = dict()
+    for subexpr in expr.subexpressions():
+        if subexpr.is_indexed and subexpr[0] == name and subexpr.get("index") in index_map:
+            attrs = {k: v for k, v in subexpr.attrs.items() if k != "index"}
+            rules[subexpr] = Expression.indexed(name, index_map[subexpr.get("index")], **attrs)
+
+    return replace_core(expr, rules) if rules else expr
+
+
+def rename_symbols(expr: Expression, fn: Callable[[str], str]) -> Expression:
+    """Rename the symbols of `expr` with the names returned by `fn`.
+
+    The symbols keep their attributes, and array elements keep their indices. The names of
+    functions and quantum operators, as well as protected symbols like `E`, are not renamed.
+
+    Example:
+    ```
+    >>> rename_symbols(theta * X(0) + RX(phi)(1), str.upper)
+    THETA * X[0] + RX(PHI)[1]
+    ```
+    """
+
+    rules = {
+        subexpr: Expression(subexpr.head, fn(subexpr[0]), *subexpr[1:], **subexpr.attrs)
+        for subexpr in expr.subexpressions()
+        if subexpr.is_symbol and subexpr[0] not in Environment.protected
+    }
+
+    return replace_core(expr, rules) if rules else expr
+
+
+def prefix_symbols(expr: Expression, prefix: str) -> Expression:
+    """Prepend `prefix` to the name of every symbol of `expr`, e.g., to avoid name clashes when
+    combining expressions built independently. See `rename_symbols` for details.
+
+    Example:
+    ```
+    >>> prefix_symbols(theta * X(0), "a_")
+    a_theta * X[0]
+    ```
+    """
+
+    return rename_symbols(expr, lambda name: prefix + name)
+
+
+def replace_operator(
+    expr: Expression, source: Expression.Tag, target: Expression.Tag
+) -> Expression:
+    """Change the tag of every subexpression tagged as `source` into `target`, keeping their
+    arguments and attributes.
+
+    This is a structural operation: the result is not evaluated and nothing checks that the new
+    operation makes sense with the given arguments, e.g., turning a three-term addition into a
+    power produces an ill-formed expression.
+
+    Example:
+    ```
+    >>> replace_operator(Expression.mul(X(0), Y(1)), Expression.Tag.MUL, Expression.Tag.KRON)
+    X[0] * Y[1]
+    ```
+    """
+
+    def swap(expr: Expression) -> Expression:
+        if expr.head != source:
+            return expr
+        return Expression(target, *expr.args, **expr.attrs)
+
+    return rewrite_bottom_up(expr, swap)
 
 
 def replace_core(expr: Expression, rules: dict[Expression, Expression]) -> Expression:
     if expr in rules:
         return rules[expr]
 
+    if expr.is_indexed and isinstance(expr.get("index"), Expression):
+        index = replace_core(expr.get("index"), rules)
+        if index is expr.get("index"):
+            return expr
+        attrs = {k: v for k, v in expr.attrs.items() if k != "index"}
+        return Expression.indexed(expr[0], index, **attrs)
+
     if expr.is_value or expr.is_symbol:
         return expr
 
     if expr.is_function:
-        name = expr[0][0]
-        args = tuple(replace_core(arg, rules) for arg in expr[1:])
-        return Expression.function(name, *args)
+        args = tuple(
+            replace_core(arg, rules) if isinstance(arg, Expression) else arg for arg in expr[1:]
+        )
+        if all(new is old for new, old in zip(args, expr[1:])):
+            return expr
+        return Expression(expr.head, expr[0], *args, **expr.attrs)
 
     if expr.is_quantum_operator:
         support = expr[1]
         operator = replace_core(expr[0], rules)
+        if operator is expr[0]:
+            return expr
         return Expression.quantum_operator(operator, support, **expr.attrs)
 
     args = tuple(replace_core(arg, rules) for arg in expr.args)
-    return Expression(expr.head, *args, **expr.attrs)
+    if all(new is old for new, old in zip(args, expr.args)):
+        return expr
+    return evaluate_node(Expression(expr.head, *args, **expr.attrs))
@@ -0,0 +1,226 @@
+from __future__ import annotations
+
+import json
+from importlib import import_module
+from typing import Any
+
+from .core.expression import Expression
+from .core.support import Support
+from .core.utils import DepthExceededError, check_depth
+from .validation import validate_wellformed
+
+# Version of the serialization format. Increase it whenever the format changes in a way older
+# readers cannot handle, and convert the older data in `from_dict` before decoding it.
+SERIALIZATION_VERSION = 1
+
+# Package whose functions can be stored as attributes, e.g., the `join` of rotations. Other
+# callables are rejected, so loading a document cannot give access to arbitrary code.
+CALLABLE_PACKAGE = "qadence2_expressions"
+
+
+def to_dict(expr: Expression, max_depth: int | None = None) -> dict[str, Any]:
+    """Convert an expression into a JSON-compatible dictionary.
+
+    The expression is wrapped in an envelope holding the version of the format. The tags are
+    stored by their canonical strings (see `Expression.Tag.__str__`), so the data doesn't depend
+    on the definition order of the tags.
+
+    Example:
+    ```
+    >>> to_dict(2 * x)
+    {'version': 1, 'expr': {'tag': '*', 'args': [...], 'attrs': {}}}
+    ```
+
+    Raises:
+        TypeError: If the expression contains objects that cannot be serialized, e.g., a `join`
+            attribute defined by a lambda function or outside of qadence2-expressions.
+        DepthExceededError: If the expression is deeper than `max_depth`.
+    """
+
+    if max_depth is not None:
+        check_depth(expr, max_depth)
+
+    return {"version": SERIALIZATION_VERSION, "expr": encode(expr)}
+
+
+def from_dict(data: dict[str, Any], max_depth: int | None = None) -> Expression:
+    """Rebuild an expression from a dictionary created with `to_dict`.
+
+    The expression is restored exactly as it was stored, without being evaluated again. The
+    nesting of the data can be limited to `max_depth` levels, so untrusted documents are rejected
+    before exhausting the stack.
+
+    Raises:
+        ValueError: If the data has no version, was written by a newer format version, doesn't
+            have the structure created by `to_dict`, or refers to a callable outside of
+            qadence2-expressions. The errors in the stored expression give the path of the
+            offending node, as the sequence of argument indices from the root.
+        SyntaxError: If the stored expression is malformed, see `validate_wellformed`.
+        DepthExceededError: If the stored expression is deeper than `max_depth`.
+    """
+
+    if not isinstance(data, dict):
+        raise ValueError(f"The serialized expression must be a dictionary, got {type(data)}.")
+
+    version = data.get("version")
+
+    if not isinstance(version, int):
+        raise ValueError("The serialized expression has no format version.")
+
+    if version > SERIALIZATION_VERSION:
+        raise ValueError(
+            f"The serialized expression uses the format version {version}, but only versions up "
+            f"to {SERIALIZATION_VERSION} are supported. Please, update qadence2-expressions."
+        )
+
+    if "expr" not in data:
+        raise ValueError("The serialized data holds no expression.")
+
+    expr = decode(data["expr"], max_depth)
+    validate_wellformed(expr)
+    return expr
+
+
+def to_json(expr: Expression, max_depth: int | None = None) -> str:
+    """Serialize an expression into a JSON string. See `to_dict` for details."""
+
+    return json.dumps(to_dict(expr, max_depth))
+
+
+def from_json(source: str, max_depth: int | None = None) -> Expression:
+    """Rebuild an expression from a JSON string created with `to_json`. See `from_dict` for
+    details.
+    """
+
+    # The JSON parser is recursive too, and fails on documents nested deeper than the stack.
+    try:
+        data = json.loads(source)
+    except RecursionError:
+        raise DepthExceededError("The serialized expression is nested too deeply.") from None
+
+    return from_dict(data, max_depth)
+
+
+def encode(item: Any) -> Any:
+    """Convert an expression, or one of its arguments or attributes, to JSON-compatible data."""
+
+    if isinstance(item, Expression):
+        return {
+            "tag": str(item.head),
+            "args": [encode(arg) for arg in item.args],
+            "attrs": {key: encode(val) for key, val in item.attrs.items()},
+        }
+
+    if item is None or isinstance(item, (bool, int, float, str)):
+        return item
+
+    if isinstance(item, complex):
+        return {"complex": [item.real, item.imag]}
+
+    if isinstance(item, Support):
+        return {"support": {"target": list(item.target), "control": list(item.control)}}
+
+    if isinstance(item, (list, tuple)):
+        return [encode(val) for val in item]
+
+    if callable(item) and _is_package_callable(item):
+        return {"callable": f"{item.__module__}:{item.__qualname__}"}
+
+    raise TypeError(f"Cannot serialize objects of type {type(item)}: {item!r}.")
+
+
+def decode(data: Any, max_depth: int | None = None, path: tuple[int, ...] = ()) -> Any:
+    """Inverse of `encode`.
+
+    Each expression and list nested in the `data` counts as one level towards `max_depth`, if
+    given, as in `Expression.depth`. The `path` is the location of the data within the stored
+    expression, as the sequence of argument indices reported in the errors.
+
+    Raises:
+        ValueError: If the data doesn't have the structure created by `encode`.
+        DepthExceededError: If the data is nested deeper than `max_depth`.
+    """
+
+    nested = isinstance(data, list) or (isinstance(data, dict) and "tag" in data)
+    if max_depth is not None and nested:
+        if max_depth < 1:
+            raise DepthExceededError("The serialized expression exceeds the maximum depth.")
+        max_depth -= 1
+
+    if isinstance(data, list):
+        return tuple(decode(val, max_depth, path) for val in data)
+
+    if data is None or isinstance(data, (bool, int, float, str)):
+        return data
+
+    if not isinstance(data, dict):
+        raise ValueError(f"Invalid serialized data at {path}: {data!r}.")
+
+    if "tag" in data:
+        tag, args, attrs = data["tag"], data.get("args"), data.get("attrs")
+        if not (isinstance(tag, str) and isinstance(args, list) and isinstance(attrs, dict)):
+            raise ValueError(f"Invalid serialized expression at {path}: {data}.")
+
+        try:
+            head = Expression.Tag.from_str(tag)
+        except ValueError:
+            raise ValueError(f"Unknown tag at {path}: {tag!r}.") from None
+
+        return Expression(
+            head,
+            *(decode(arg, max_depth, (*path, i)) for i, arg in enumerate(args)),
+            **{str(key): decode(val, max_depth, path) for key, val in attrs.items()},
+        )
+
+    if "complex" in data:
+        parts = data["complex"]
+        if not (isinstance(parts, list) and len(parts) == 2 and all(map(_is_real, parts))):
+            raise ValueError(f"Invalid serialized complex number at {path}: {data}.")
+        return complex(*parts)
+
+    if "support" in data:
+        support = data["support"]
+        if not (
+            isinstance(support, dict)
+            and all(_is_qubit_list(support.get(key)) for key in ("target", "control"))
+        ):
+            raise ValueError(f"Invalid serialized support at {path}: {data}.")
+        return Support(target=tuple(support["target"]), control=tuple(support["control"]))
+
+    if "callable" in data:
+        if not isinstance(data["callable"], str):
+            raise ValueError(f"Invalid serialized callable at {path}: {data}.")
+
+        module, _, name = data["callable"].partition(":")
+        if not _in_package(module):
+            raise ValueError(f"Only callables of {CALLABLE_PACKAGE} can be decoded, got {data}.")
+
+        obj: Any = import_module(module)
+        for attr in name.split("."):
+            obj = getattr(obj, attr, None)
+
+        # Objects imported from other modules, e.g., `import_module` itself, are rejected too.
+        if not (callable(obj) and _is_package_callable(obj) and obj.__qualname__ == name):
+            raise ValueError(f"Invalid serialized callable at {path}: {data}.")
+        return obj
+
+    raise ValueError(f"Invalid serialized data at {path}: {data}.")
+
+
+def _is_real(item: Any) -> bool:
+    return isinstance(item, (int, float)) and not isinstance(item, bool)
+
+
+def _is_qubit_list(item: Any) -> bool:
+    return isinstance(item, list) and all(
+        isinstance(q, int) and not isinstance(q, bool) for q in item
+    )
+
+
+def _in_package(module: str) -> bool:
+    return module == CALLABLE_PACKAGE or module.startswith(f"{CALLABLE_PACKAGE}.")
+
+
+def _is_package_callable(item: Any) -> bool:
+    qualname = getattr(item, "__qualname__", "<")
+    return "<" not in qualname and _in_package(getattr(item, "__module__", None) or "")
@@ -0,0 +1,581 @@
+from __future__ import annotations
+
+import cmath
+import logging
+import math
+from collections import Counter
+from typing import Callable
+
+from .core.constructors import promote
+from .core.expression import Expression
+from .core.utils import Numeric, check_depth
+
+logger = logging.getLogger(__name__)
+
+
+def prune_zeros(expr: Expression) -> Expression:
+    """Remove the terms with a zero coefficient left in an expression.
+
+    Additions drop their zero terms and products with a zero factor are replaced by zero,
+    recursively. Unlike a full evaluation, nothing else is rewritten, which makes this pass cheap
+    to apply to large expressions, e.g., after binding some of the parameters of a Hamiltonian.
+
+    Example:
+    ```
+    >>> prune_zeros(Expression.add(X(0), Expression.mul(value(0), X(1)), X(2)))
+    X(0) + X(2)
+    ```
+    """
+
+    def prune(expr: Expression) -> Expression:
+        if expr.is_addition:
+            terms = tuple(term for term in expr.args if not term.is_zero)
+            if len(terms) == len(expr.args):
+                return expr
+            if not terms:
+                return Expression.zero()
+            return terms[0] if len(terms) == 1 else Expression(expr.head, *terms, **expr.attrs)
+
+        if (expr.is_multiplication or expr.is_kronecker_product) and any(
+            factor.is_zero for factor in expr.args
+        ):
+            return Expression.zero()
+
+        return expr
+
+    return rewrite_bottom_up(expr, prune)
+
+
+def collapse_singletons(expr: Expression) -> Expression:
+    """Replace the sums and products with a single argument by the argument itself, and the empty
+    ones by their identity, zero for sums and one for products.
+
+    Such nodes are left by transformations building expressions without evaluation, e.g., after
+    filtering the terms of a sum.
+
+    Example:
+    ```
+    >>> collapse_singletons(Expression.add(Expression.mul(x), Expression.mul()))
+    x + 1.0
+    ```
+    """
+
+    def collapse(expr: Expression) -> Expression:
+        if not (expr.is_addition or expr.is_multiplication or expr.is_kronecker_product):
+            return expr
+
+        if len(expr.args) == 1:
+            return expr[0]  # type: ignore [no-any-return]
+
+        if not expr.args:
+            return Expression.zero() if expr.is_addition else Expression.one()
+
+        return expr
+
+    return rewrite_bottom_up(expr, collapse)
+
+
+def combine_like_terms(expr: Expression) -> Expression:
+    """Merge the terms of the sums that are equal up to their numerical coefficients.
+
+    The terms are split into their numerical coefficients and the remaining factors, and the
+    coefficients of equal terms are added, e.g., `x + 2 * x = 3 * x`. Terms whose coefficients
+    add up to zero are dropped. Like `prune_zeros`, nothing else is rewritten, so it applies to
+    expressions built without evaluation.
+
+    Example:
+    ```
+    >>> combine_like_terms(Expression.add(x, Expression.mul(value(2), x), y))
+    3.0 * x + y
+    ```
+    """
+
+    def combine(expr: Expression) -> Expression:
+        if not expr.is_addition:
+            return expr
+
+        coefficients = _coefficients_by_term(expr)
+        if len(coefficients) == len(expr.args):
+            return expr
+
+        terms = []
+        for term, coefficient in coefficients.items():
+            if term.is_one:
+                terms.append(Expression.value(coefficient))
+            elif coefficient == 1:
+                terms.append(term)
+            elif term.is_multiplication and term.is_commutative:
+                terms.append(
+                    Expression(term.head, Expression.value(coefficient), *term.args, **term.attrs)
+                )
+            else:
+                terms.append(Expression.mul(Expression.value(coefficient), term))
+
+        if not terms:
+            return Expression.zero()
+
+        return terms[0] if len(terms) == 1 else Expression(expr.head, *terms, **expr.attrs)
+
+    return rewrite_bottom_up(expr, combine)
+
+
+def drop_small_terms(
+    expr: Expression, threshold: float, return_dropped: bool = False
+) -> Expression | tuple[Expression, float]:
+    """Remove the terms of a sum whose numerical coefficient has a magnitude below `threshold`.
+
+    The coefficient of a term is the product of its numerical factors; the remaining factors must
+    be quantum operators. Terms with symbolic coefficients are always kept. Only the top-level
+    sum is truncated, as in a Hamiltonian written as a sum of Pauli strings.
+
+    Example:
+    ```
+    >>> drop_small_terms(0.5 * Z(0) + 0.001 * X(0), 0.01)
+    0.5 * Z[0]
+    >>> expr, dropped = drop_small_terms(0.5 * Z(0) + 0.001 * X(0), 0.01, return_dropped=True)
+    >>> dropped
+    0.001
+    ```
+
+    Args:
+        expr: The expression to truncate.
+        threshold: The smallest coefficient magnitude to keep.
+        return_dropped: Also return the sum of the magnitudes of the dropped coefficients, which
+            bounds the error introduced by the truncation.
+    """
+
+    kept = []
+    dropped = 0.0
+
+    for term in expr.args if expr.is_addition else (expr,):
+        factors = term.args if term.is_multiplication else (term,)
+
+        if any(not factor.is_value and factor.subspace is None for factor in factors):
+            kept.append(term)
+            continue
+
+        magnitude = abs(math.prod(factor[0] for factor in factors if factor.is_value))
+        if magnitude < threshold:
+            dropped += magnitude
+        else:
+            kept.append(term)
+
+    if len(kept) == len(expr.args if expr.is_addition else (expr,)):
+        result = expr
+    else:
+        result = sum(kept, Expression.zero())
+
+    return (result, dropped) if return_dropped else result
+
+
+def equals_up_to_scalar(lhs: Expression, rhs: Expression) -> Numeric | None:
+    """Check whether `lhs` is equal to `rhs` times a numerical constant.
+
+    The terms of both expressions are split into their numerical coefficients and the remaining
+    factors. The expressions are proportional when the same terms appear on both sides with a
+    constant ratio between their coefficients. Different ways of writing the same expression may
+    not be recognised.
+
+    Example:
+    ```
+    >>> equals_up_to_scalar(2 * X(0), 3 * X(0))
+    0.6666666666666666
+    >>> equals_up_to_scalar(X(0), Y(0))
+    None
+    ```
+
+    Returns:
+        The ratio `lhs / rhs`, or `None` if the expressions are not proportional.
+    """
+
+    lhs_terms = _coefficients_by_term(lhs)
+    rhs_terms = _coefficients_by_term(rhs)
+
+    if not rhs_terms:
+        return None
+
+    if not lhs_terms:
+        return 0.0
+
+    if lhs_terms.keys() != rhs_terms.keys():
+        return None
+
+    ratios = [lhs_terms[term] / rhs_terms[term] for term in rhs_terms]
+    if all(cmath.isclose(ratio, ratios[0]) for ratio in ratios):
+        return ratios[0]
+
+    return None
+
+
+def _coefficients_by_term(expr: Expression) -> dict[Expression, Numeric]:
+    """Map the terms of a sum, stripped from their numerical factors, to their coefficients."""
+
+    result: dict[Expression, Numeric] = dict()
+
+    for term in expr.args if expr.is_addition else (expr,):
+        # The order of noncommutative factors is kept, only their leading coefficient is removed.
+        if not (term.is_multiplication and term.is_commutative):
+            key, coefficient = term.without_coefficient(), term.coefficient()
+            result[key] = result.get(key, 0.0) + coefficient
+            continue
+
+        coefficient = math.prod(factor[0] for factor in term.args if factor.is_value)
+        others = [factor for factor in term.args if not factor.is_value]
+
+        if not others:
+            key = Expression.one()
+        elif len(others) == 1:
+            key = others[0]
+        else:
+            key = Expression(term.head, *others, **term.attrs)
+
+        result[key] = result.get(key, 0.0) + coefficient
+
+    return {term: coefficient for term, coefficient in result.items() if coefficient != 0}
+
+
+def fold_signs(expr: Expression) -> Expression:
+    """Gather the numerical factors of the products, like the `-1` factors left by chains of
+    negations, into a single coefficient.
+
+    Nested commutative products are flattened, and the numerical factors are multiplied into a
+    single leading coefficient, which is dropped when it is equal to one. Thus, an even number of
+    negations vanishes and an odd number becomes a single `-1` factor.
+
+    Example:
+    ```
+    >>> fold_signs(Expression.mul(value(-1), Expression.mul(value(-1), value(-1), x)))
+    -x
+    ```
+    """
+
+    def fold(expr: Expression) -> Expression:
+        if not (expr.is_multiplication and expr.is_commutative):
+            return expr
+
+        factors = []
+        for arg in expr.args:
+            nested = arg.is_multiplication and arg.is_commutative and arg.attrs == expr.attrs
+            factors.extend(arg.args if nested else (arg,))
+
+        # Products with a single leading coefficient, other than one, are already folded.
+        values = [factor for factor in factors if factor.is_value]
+        if len(factors) == len(expr.args) and (
+            not values or (len(values) == 1 and values[0] is expr[0] and not expr[0].is_one)
+        ):
+            return expr
+
+        coefficient = math.prod(factor[0] for factor in values)
+        others = [factor for factor in factors if not factor.is_value]
+
+        if coefficient == 0 or not others:
+            return Expression.value(coefficient)
+
+        if coefficient != 1:
+            others.insert(0, Expression.value(coefficient))
+
+        if len(others) == 1:
+            return others[0]
+
+        return Expression(expr.head, *others, **expr.attrs)
+
+    return rewrite_bottom_up(expr, fold)
+
+
+def fold_powers(expr: Expression) -> Expression:
+    """Merge the numerical exponents of nested powers, `(x^a)^b = x^(a * b)`.
+
+    Only numerical exponents are merged, so a symbolic exponent stops the folding, e.g.,
+    `((x^2)^3)^n` becomes `(x^6)^n`. Powers with a merged exponent of one or zero are replaced by
+    their base or by one, respectively. Like the `**` operator, the merge doesn't check that the
+    base is positive for non-integer exponents.
+
+    Example:
+    ```
+    >>> fold_powers(Expression.pow(Expression.pow(x, value(2)), value(3)))
+    x ^ 6.0
+    ```
+    """
+
+    def fold(expr: Expression) -> Expression:
+        if not (expr.is_power and expr[1].is_value):
+            return expr
+
+        base, power = expr.args
+        if base.is_power and base[1].is_value:
+            base, power = base[0], Expression.value(base[1][0] * power[0])
+        elif not (power.is_one or power.is_zero):
+            return expr
+
+        if power.is_one:
+            return base
+
+        if power.is_zero:
+            return Expression.one()
+
+        return Expression(expr.head, base, power, **expr.attrs)
+
+    return rewrite_bottom_up(expr, fold)
+
+
+def expand_integer_power(expr: Expression, max_exponent: int = 8) -> Expression:
+    """Replace the powers with a non-negative integer exponent up to `max_exponent` by explicit
+    products of their base, e.g., `x^3 = x * (x * x)`.
+
+    The products are built by repeated squaring without evaluation, otherwise they would be
+    folded back into powers, and the squares are shared, so the result has a depth and a number
+    of distinct nodes logarithmic in the exponent. Symbolic, negative, non-integer, and larger
+    exponents are kept as powers.
+
+    Example:
+    ```
+    >>> expand_integer_power(x**3)
+    x * (x * x)
+    >>> expand_integer_power(x**100)
+    x ^ 100.0
+    ```
+    """
+
+    def expand(expr: Expression) -> Expression:
+        if not (expr.is_power and expr[1].is_value):
+            return expr
+
+        base, power = expr[0], expr[1][0]
+        if (
+            isinstance(power, complex)
+            or not float(power).is_integer()
+            or not 0 <= power <= max_exponent
+        ):
+            return expr
+
+        result: Expression | None = None
+        square = base
+        n = int(power)
+        while n:
+            if n % 2:
+                result = square if result is None else Expression.mul(result, square)
+            n //= 2
+            if n:
+                square = Expression.mul(square, square)
+
+        return Expression.one() if result is None else result
+
+    return rewrite_bottom_up(expr, expand)
+
+
+def rationalize_denominators(expr: Expression) -> Expression:
+    """Remove the imaginary unit from the denominators by multiplying them by their conjugates,
+    `1 / z = conj(z) / (z * conj(z))`.
+
+    Symbols and functions are assumed to be real, as in `Expression.dag`, so the new denominators
+    are real. Only classical powers with negative integer exponents are rewritten.
+
+    Example:
+    ```
+    >>> rationalize_denominators(1 / (x + 1j))
+    -1j * (1.0 + x ^ 2.0) ^ -1.0 + x * (1.0 + x ^ 2.0) ^ -1.0
+    ```
+    """
+
+    def rationalize(expr: Expression) -> Expression:
+        if not (
+            expr.is_power
+            and expr[1].is_value
+            and not isinstance(expr[1][0], complex)
+            and expr[1][0] < 0
+            and float(expr[1][0]).is_integer()
+            and expr.subspace is None
+        ):
+            return expr
+
+        base, power = expr.args
+        conjugate = base.dag
+        if conjugate == base:
+            return expr
+
+        norm = drop_zero_imaginary(prune_zeros(base * conjugate))
+        return conjugate ** (-power) * norm**power  # type: ignore
+
+    return rewrite_bottom_up(expr, rationalize)
+
+
+def drop_zero_imaginary(expr: Expression) -> Expression:
+    """Convert the complex values with a null imaginary part into real values."""
+
+    def convert(expr: Expression) -> Expression:
+        if expr.is_value and isinstance(expr[0], complex) and expr[0].imag == 0:
+            return Expression.value(expr[0].real)
+        return expr
+
+    return rewrite_bottom_up(expr, convert)
+
+
+def simplify_trig(expr: Expression) -> Expression:
+    """Apply a conservative set of trigonometric identities.
+
+    The rules are `sin(0) = 0`, `cos(0) = 1`, `sin(-x) = -sin(x)`, `cos(-x) = cos(x)`, and
+    `a * sin(x)^2 + a * cos(x)^2 = a`, where `a` are the remaining factors of the terms, which
+    must be the same. Arguments are negative when their leading coefficient is a negative real
+    number.
+
+    Example:
+    ```
+    >>> simplify_trig(y * sin(x)**2 + y * cos(x)**2 + cos(-x))
+    y + cos(x)
+    ```
+    """
+
+    def rule(expr: Expression) -> Expression:
+        if expr.is_function and expr[0][0] in ("sin", "cos") and len(expr.args) == 2:
+            name, arg = expr[0][0], promote(expr[1])
+
+            if arg.is_zero:
+                return Expression.zero() if name == "sin" else Expression.one()
+
+            if (
+                arg.is_multiplication
+                and arg[0].is_value
+                and not isinstance(arg[0][0], complex)
+                and arg[0][0] < 0
+            ):
+                result = Expression.function(name, -arg)
+                return -result if name == "sin" else result
+
+        # Operations are evaluated again to combine the rewritten arguments.
+        if expr.is_addition:
+            return _pythagorean_identity(sum(expr.args, Expression.zero()))
+
+        if expr.is_multiplication:
+            result = Expression.one()
+            for arg in expr.args:
+                result = result * arg
+            return result
+
+        if expr.is_power:
+            return expr[0] ** expr[1]  # type: ignore [no-any-return]
+
+        return expr
+
+    return rewrite_bottom_up(expr, rule)
+
+
+def _pythagorean_identity(expr: Expression) -> Expression:
+    """Replace the pairs of terms `a * sin(x)^2 + a * cos(x)^2` of a sum by `a`."""
+
+    if not expr.is_addition:
+        return expr
+
+    def split(term: Expression) -> tuple[str, Expression, frozenset, list[Expression]] | None:
+        factors = term.args if term.is_multiplication and term.is_commutative else (term,)
+        for i, factor in enumerate(factors):
+            if (
+                factor.is_power
+                and factor[1] == 2
+                and factor[0].is_function
+                and factor[0][0][0] in ("sin", "cos")
+                and len(factor[0].args) == 2
+            ):
+                rest = [*factors[:i], *factors[i + 1 :]]
+                return factor[0][0][0], factor[0][1], frozenset(Counter(rest).items()), rest
+        return None
+
+    sines: dict[tuple[Expression, frozenset], int] = dict()
+    cosines: dict[tuple[Expression, frozenset], int] = dict()
+    splits = [split(term) for term in expr.args]
+
+    for i, parts in enumerate(splits):
+        if parts is not None:
+            name, arg, key, _ = parts
+            (sines if name == "sin" else cosines).setdefault((arg, key), i)
+
+    pairs = {sines[key]: cosines[key] for key in sines.keys() & cosines.keys()}
+    if not pairs:
+        return expr
+
+    paired = set(pairs.values())
+    result = Expression.zero()
+
+    for i, term in enumerate(expr.args):
+        if i in pairs:
+            rest = splits[i][3]  # type: ignore [index]
+            factor = Expression.one()
+            for arg in rest:
+                factor = factor * arg
+            result = result + factor
+        elif i not in paired:
+            result = result + term
+
+    return result
+
+
+def rewrite_bottom_up(
+    expr: Expression,
+    rule: Callable[[Expression], Expression],
+    max_depth: int | None = None,
+) -> Expression:
+    """Apply the `rule` to every subexpression, starting from the leaves.
+
+    The `rule` receives the node with its arguments already rewritten. As in `replace`, the
+    unchanged subexpressions are reused as they are.
+
+    Each rewrite is logged at the `DEBUG` level of the `qadence2_expressions.simplify` logger,
+    with the name of the rule, the step within the pass, and the subexpression before and after,
+    to follow the trajectory of a simplification. The rule is called directly when the level is
+    disabled.
+
+    The traversal is recursive, so the expression is first checked against `max_depth`, if given,
+    to reject deeply nested inputs before exhausting the stack.
+
+    Raises:
+        DepthExceededError: If the expression is deeper than `max_depth`.
+    """
+
+    if max_depth is not None:
+        check_depth(expr, max_depth)
+
+    if logger.isEnabledFor(logging.DEBUG):
+        rule = _traced(rule)
+
+    return _rewrite_bottom_up(expr, rule)
+
+
+def _traced(rule: Callable[[Expression], Expression]) -> Callable[[Expression], Expression]:
+    name = getattr(rule, "__qualname__", repr(rule))
+    step = 0
+
+    def traced(expr: Expression) -> Expression:
+        nonlocal step
+
+        result = rule(expr)
+        if result is not expr and result != expr:
+            step += 1
+            logger.debug("%s, step %d: %s -> %s", name, step, expr, result)
+        return result
+
+    return traced
+
+
+def _rewrite_bottom_up(expr: Expression, rule: Callable[[Expression], Expression]) -> Expression:
+    if expr.is_value or expr.is_symbol:
+        return rule(expr)
+
+    if expr.is_function:
+        args = tuple(
+            _rewrite_bottom_up(arg, rule) if isinstance(arg, Expression) else arg
+            for arg in expr[1:]
+        )
+        if any(new is not old for new, old in zip(args, expr[1:])):
+            expr = Expression(expr.head, expr[0], *args, **expr.attrs)
+        return rule(expr)
+
+    if expr.is_quantum_operator:
+        if not (expr[0].is_symbol or expr[0].is_function):
+            operator = _rewrite_bottom_up(expr[0], rule)
+            if operator is not expr[0]:
+                expr = Expression(expr.head, operator, *expr[1:], **expr.attrs)
+        return rule(expr)
+
+    args = tuple(_rewrite_bottom_up(arg, rule) for arg in expr.args)
+    if any(new is not old for new, old in zip(args, expr.args)):
+        expr = Expression(expr.head, *args, **expr.attrs)
+    return rule(expr)
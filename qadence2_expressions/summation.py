@@ -0,0 +1,186 @@
+from __future__ import annotations
+
+from typing import Sequence
+
+from .core.constructors import promote
+from .core.expression import Expression
+from .core.utils import Numeric
+from .replace import evaluate_node, replace
+from .simplify import rewrite_bottom_up
+
+
+def summation(
+    index: str,
+    lower: Expression | Numeric,
+    upper: Expression | Numeric,
+    body: Expression,
+) -> Expression:
+    """Symbolic representation of the sum of `body` for `index` going from `lower` to `upper`,
+    both included.
+
+    The summation is represented as the function `sum(body, index, lower, upper)` and can be
+    materialised with `expand_sum` once the bounds are known. The function holds the name of the
+    index in its `bound_index` attribute, which distinguishes it from user functions named `sum`
+    and excludes the index from the free symbols.
+
+    Example:
+    ```
+    >>> i = parameter("i")
+    >>> summation("i", 0, n, indexed("x", i))
+    sum(x[i], i, 0.0, n)
+    ```
+
+    Args:
+        index: The name of the summation index.
+        lower: The first value of the index.
+        upper: The last value of the index.
+        body: The expression to sum, usually depending on the index.
+
+    Returns:
+        A `Function(Symbol('sum'), body, Symbol(index), lower, upper, bound_index=index)`
+        expression.
+    """
+
+    return Expression(
+        Expression.Tag.FN,
+        Expression.symbol("sum"),
+        body,
+        Expression.symbol(index),
+        promote(lower),
+        promote(upper),
+        bound_index=index,
+    )
+
+
+def is_summation(expr: Expression) -> bool:
+    """Returns true if the expression was created with `summation`."""
+    return expr.is_function and expr[0][0] == "sum" and expr.get("bound_index") is not None
+
+
+def expand_sum(expr: Expression) -> Expression:
+    """Replace the summations whose bounds are integers by explicit additions, substituting the
+    index in the body by its values. Summations with symbolic bounds are kept.
+
+    Example:
+    ```
+    >>> expand_sum(summation("i", 0, 2, indexed("x", i)))
+    x[0] + x[1] + x[2]
+    ```
+    """
+
+    if expr.is_value or expr.is_symbol:
+        return expr
+
+    args = tuple(expand_sum(arg) if isinstance(arg, Expression) else arg for arg in expr.args)
+
+    if is_summation(expr):
+        body, index, lower, upper = args[1:]
+        if is_integer(lower) and is_integer(upper):
+            values = range(int(lower[0]), int(upper[0]) + 1)
+            return sum(
+                (
+                    reduce_index_functions(replace(body, {index: Expression.value(k)}))
+                    for k in values
+                ),
+                start=Expression.zero(),
+            )
+
+    if all(new is old for new, old in zip(args, expr.args)):
+        return expr
+
+    return evaluate_node(Expression(expr.head, *args, **expr.attrs))
+
+
+def is_integer(expr: Expression) -> bool:
+    return expr.is_value and not isinstance(expr[0], complex) and float(expr[0]).is_integer()
+
+
+def kronecker_delta(i: Expression | Numeric, j: Expression | Numeric) -> Expression:
+    """The Kronecker delta, one if the indices are equal and zero otherwise.
+
+    The delta is evaluated when the indices are integers or the same expression, and kept as the
+    function `delta(i, j)` otherwise, e.g., to be reduced once the indices are substituted by
+    `expand_sum`. The function is marked with the `index_symbol` attribute, so it is not confused
+    with user functions named `delta`.
+
+    Example:
+    ```
+    >>> kronecker_delta(2, 2)
+    1.0
+    >>> kronecker_delta(i, 2)
+    delta(i, 2.0)
+    ```
+    """
+
+    i, j = promote(i), promote(j)
+
+    if i == j:
+        return Expression.one()
+
+    if is_integer(i) and is_integer(j):
+        return Expression.zero()
+
+    return Expression(Expression.Tag.FN, Expression.symbol("delta"), i, j, index_symbol=True)
+
+
+def levi_civita(*indices: Expression | Numeric) -> Expression:
+    """The Levi-Civita symbol, the sign of the permutation sorting the indices, or zero if any
+    index is repeated.
+
+    The symbol is evaluated when the indices are integers or some of them are the same
+    expression, and kept as the function `epsilon(i, j, ...)` otherwise, marked with the
+    `index_symbol` attribute as in `kronecker_delta`.
+
+    Example:
+    ```
+    >>> levi_civita(2, 1, 3)
+    -1.0
+    >>> levi_civita(i, j, i)
+    0.0
+    ```
+    """
+
+    args = tuple(promote(index) for index in indices)
+
+    if len(set(args)) < len(args):
+        return Expression.zero()
+
+    if all(is_integer(arg) for arg in args):
+        return Expression.value(permutation_sign([arg[0] for arg in args]))
+
+    return Expression(Expression.Tag.FN, Expression.symbol("epsilon"), *args, index_symbol=True)
+
+
+def reduce_index_functions(expr: Expression) -> Expression:
+    """Evaluate the Kronecker deltas and Levi-Civita symbols whose indices became concrete, e.g.,
+    after a substitution.
+
+    Example:
+    ```
+    >>> reduce_index_functions(replace(kronecker_delta(i, 2) * x, {i: value(2)}))
+    x
+    ```
+    """
+
+    def reduce(expr: Expression) -> Expression:
+        if not (expr.is_function and expr.get("index_symbol")):
+            return evaluate_node(expr)
+
+        if expr[0][0] == "delta":
+            return kronecker_delta(expr[1], expr[2])
+
+        return levi_civita(*expr[1:])
+
+    return rewrite_bottom_up(expr, reduce)
+
+
+def permutation_sign(values: Sequence[Numeric]) -> int:
+    """Returns the sign of the permutation sorting the `values`, or zero if any is repeated."""
+
+    if len(set(values)) < len(values):
+        return 0
+
+    inversions = sum(
+        1 for a in range(len(values)) for b in range(a + 1, len(values)) if values[a] > values[b]
+    )
+    return -1 if inversions % 2 else 1
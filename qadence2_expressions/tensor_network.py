@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from string import ascii_letters
+
+from .core.expression import Expression
+from .core.utils import Numeric
+
+
+@dataclass(frozen=True)
+class Tensor:
+    """A quantum operator of a `TensorNetwork` with the labels of its indices.
+
+    The `indices` are the output indices followed by the input indices, one of each per qubit
+    in the order of `qubits`. This matches the operator's matrix, with the first qubit as the most
+    significant bit, reshaped into a tensor of shape `(2,) * 2 * len(qubits)`.
+    """
+
+    operator: Expression
+    qubits: tuple[int, ...]
+    indices: tuple[str, ...]
+
+
+@dataclass(frozen=True)
+class TensorNetwork:
+    """A product of quantum operators as a network of tensors connected by shared indices.
+
+    The `tensors` are listed in the order they are applied, i.e., from right to left in the
+    product. The open indices of each qubit are given by `inputs` and `outputs`.
+    """
+
+    tensors: tuple[Tensor, ...]
+    inputs: dict[int, str]
+    outputs: dict[int, str]
+    scale: Numeric = 1.0
+
+    def subscripts(self) -> str:
+        """Returns the contraction of the network in the `einsum` notation, e.g., for NumPy.
+
+        The result has the output indices followed by the input indices, ordered by qubit.
+
+        Raises:
+            ValueError: If the network has more indices than the available letters.
+        """
+
+        labels: dict[str, str] = {}
+
+        def letters(indices: tuple[str, ...]) -> str:
+            for index in indices:
+                if index not in labels:
+                    if len(labels) == len(ascii_letters):
+                        raise ValueError("Too many indices for the einsum notation.")
+                    labels[index] = ascii_letters[len(labels)]
+            return "".join(labels[index] for index in indices)
+
+        operands = ",".join(letters(tensor.indices) for tensor in self.tensors)
+        qubits = sorted(self.outputs)
+        result = letters((*(self.outputs[q] for q in qubits), *(self.inputs[q] for q in qubits)))
+        return f"{operands}->{result}"
+
+
+def to_tensor_network(expr: Expression) -> TensorNetwork:
+    """Returns the tensor network of a product of quantum operators.
+
+    Each operator becomes a tensor acting on its target and control qubits. The index labels are
+    derived from the qubits, the `k`-th index of the qubit `i` being `"q{i}_{k}"`, so consecutive
+    operators on the same qubit share an index. A numerical coefficient is kept as the `scale`
+    of the network.
+
+    Example:
+    ```
+    >>> network = to_tensor_network(X(0) * Z(0))
+    >>> [tensor.indices for tensor in network.tensors]
+    [('q0_1', 'q0_0'), ('q0_2', 'q0_1')]
+    >>> network.subscripts()
+    'ab,ca->cb'
+    ```
+
+    Raises:
+        ValueError: If the expression is not a product of quantum operators, or an operator acts
+            on all the qubits, as the number of qubits is unknown.
+    """
+
+    scale: Numeric = 1.0
+    if expr.is_multiplication and len(expr.args) == 2 and expr[0].is_value:
+        scale, expr = expr[0][0], expr[1]
+
+    if expr.is_quantum_operator:
+        operators: tuple[Expression, ...] = (expr,)
+    elif expr.is_kronecker_product:
+        operators = expr.args
+    else:
+        raise ValueError(f"Only products of quantum operators can be converted, got {expr}.")
+
+    counts: dict[int, int] = {}
+    tensors = []
+
+    # The rightmost operator of the product is applied first.
+    for operator in reversed(operators):
+        support = operator[1]
+        if not support.target:
+            raise ValueError(f"The operator {operator} acts on all the qubits.")
+
+        qubits = (*support.target, *support.control)
+        inputs = tuple(f"q{q}_{counts.get(q, 0)}" for q in qubits)
+        for q in qubits:
+            counts[q] = counts.get(q, 0) + 1
+        outputs = tuple(f"q{q}_{counts[q]}" for q in qubits)
+
+        tensors.append(Tensor(operator, qubits, (*outputs, *inputs)))
+
+    return TensorNetwork(
+        tensors=tuple(tensors),
+        inputs={q: f"q{q}_0" for q in sorted(counts)},
+        outputs={q: f"q{q}_{counts[q]}" for q in sorted(counts)},
+        scale=scale,
+    )
@@ -0,0 +1,160 @@
+from __future__ import annotations
+
+from typing import Iterator
+
+from .core.expression import Expression
+from .core.support import Support
+from .core.utils import Numeric
+from .simplify import rewrite_bottom_up
+
+Path = tuple[int, ...]
+
+
+def non_finite_paths(expr: Expression) -> list[Path]:
+    """Returns the location of the numerical values that are infinite or `NaN`.
+
+    Each location is the sequence of argument indices leading from `expr` to the value, so the
+    value at `(1, 0)` is `expr[1][0]`. The empty path refers to `expr` itself.
+
+    Example:
+    ```
+    >>> non_finite_paths(x + value(inf) * y)
+    [(1, 0)]
+    ```
+    """
+
+    return list(_non_finite_paths(expr, ()))
+
+
+def _non_finite_paths(expr: Expression, path: Path) -> Iterator[Path]:
+    if expr.is_value:
+        if not expr.is_finite:
+            yield path
+        return
+
+    for i, arg in enumerate(expr.args):
+        if isinstance(arg, Expression):
+            yield from _non_finite_paths(arg, (*path, i))
+
+
+def validate(expr: Expression) -> None:
+    """Check that all the numerical values in the expression are finite.
+
+    Use it before exporting an expression, e.g., to generate code for a backend, to catch
+    ill-defined values introduced by a bad substitution.
+
+    Raises:
+        ValueError: If the expression contains infinite or `NaN` values. The message reports their
+            locations as given by `non_finite_paths`.
+    """
+
+    paths = non_finite_paths(expr)
+
+    if paths:
+        locations = ", ".join(f"{expr.get_at(path)} at {list(path)}" for path in paths)
+        raise ValueError(f"The expression {expr} has non-finite values: {locations}.")
+
+
+def replace_non_finite(expr: Expression, default: Numeric) -> tuple[Expression, int]:
+    """Replace the infinite and `NaN` values in the expression by `default`.
+
+    Use it instead of `validate` to continue with a safe value when a substitution produced
+    ill-defined values. The structure of the expression is preserved, so a replaced value is not
+    combined with the rest of its sum or product.
+
+    Example:
+    ```
+    >>> expr, count = replace_non_finite(x + value(inf) * y, 0)
+    >>> expr
+    x + 0.0 * y
+    >>> count
+    1
+    ```
+
+    Returns:
+        The expression with the non-finite values replaced, and the number of replacements.
+    """
+
+    count = 0
+
+    def rule(expr: Expression) -> Expression:
+        nonlocal count
+
+        if expr.is_value and not expr.is_finite:
+            count += 1
+            return Expression.value(default)
+        return expr
+
+    return rewrite_bottom_up(expr, rule), count
+
+
+def validate_wellformed(expr: Expression) -> None:
+    """Check that every node of the expression has the structure expected for its tag.
+
+    Values hold a number and symbols a name. Functions start with the symbol of their name,
+    followed by expressions or numbers, e.g., the angle of a parametric operator. Quantum
+    operators hold an operator and a `Support`. Additions, multiplications, and Kronecker products
+    have at least two expressions as arguments, the latter of quantum operators, and powers have
+    exactly two. Expressions built with the operators or the checked constructors are always
+    well-formed, but trees built manually or deserialized may not be.
+
+    Raises:
+        SyntaxError: If a node is malformed. The message reports the location of the first one, as
+            given by `Expression.walk_paths`.
+    """
+
+    for path, node in expr.walk_paths():
+        problem = _structural_problem(node)
+        if problem is not None:
+            raise SyntaxError(f"Malformed expression at {list(path)}: {problem}.")
+
+
+def _structural_problem(expr: Expression) -> str | None:
+    args = expr.args
+    tag = expr.head.value
+
+    if expr.is_value:
+        if len(args) != 1 or isinstance(args[0], bool) or not isinstance(args[0], Numeric):
+            return "a value must hold a single number"
+        return None
+
+    if expr.is_symbol:
+        if len(args) != 1 or not isinstance(args[0], str):
+            return "a symbol must hold a single name"
+        return None
+
+    if expr.is_function:
+        if not (args and isinstance(args[0], Expression) and args[0].is_symbol):
+            return "a function must start with the symbol of its name"
+        if not all(_is_function_argument(arg) for arg in args[1:]):
+            return "the arguments of a function must be expressions or numbers"
+        return None
+
+    if expr.is_quantum_operator:
+        if len(args) != 2 or not isinstance(args[0], Expression):
+            return "a quantum operator must hold an operator and its support"
+        if not isinstance(args[1], Support):
+            return f"the support of a quantum operator must be a Support, got {type(args[1])}"
+        return None
+
+    if not all(isinstance(arg, Expression) for arg in args):
+        return f"the arguments of {tag} must be expressions"
+
+    if expr.is_power:
+        if len(args) != 2:
+            return f"{tag} requires exactly 2 arguments, got {len(args)}"
+        return None
+
+    if len(args) < 2:
+        return f"{tag} requires at least 2 arguments, got {len(args)}"
+
+    if expr.is_kronecker_product and not all(arg.is_quantum_operator for arg in args):
+        return f"the arguments of {tag} must be quantum operators"
+
+    return None
+
+
+def _is_function_argument(arg: object) -> bool:
+    if isinstance(arg, Expression):
+        return True
+    return isinstance(arg, Numeric) and not isinstance(arg, bool)
@@ -1 +1,63 @@
 from __future__ import annotations
+
+from functools import reduce
+
+from hypothesis import strategies as st
+
+from qadence2_expressions import (
+    Expression,
+    cos,
+    sin,
+    symbol,
+    value,
+)
+
+SYMBOL_NAMES = ["x", "y", "z"]
+
+symbols = st.sampled_from(SYMBOL_NAMES).map(symbol)
+
+numbers = st.one_of(
+    st.integers(min_value=-5, max_value=5),
+    st.floats(min_value=-10, max_value=10, allow_nan=False, allow_infinity=False),
+)
+
+values = numbers.map(value)
+
+leaves = st.one_of(values, symbols)
+
+exponents = st.integers(min_value=-3, max_value=3).map(value)
+
+# Negative powers of zero values raise an error when evaluated.
+positive_exponents = st.integers(min_value=0, max_value=3).map(value)
+
+
+def raw_operations(children: st.SearchStrategy[Expression]) -> st.SearchStrategy[Expression]:
+    """Combine the `children` into operation nodes built without evaluation."""
+
+    terms = st.lists(children, min_size=2, max_size=3)
+
+    return st.one_of(
+        terms.map(lambda args: Expression.add(*args)),
+        terms.map(lambda args: Expression.mul(*args)),
+        st.tuples(children, exponents).map(lambda args: Expression.pow(*args)),
+        children.map(cos),
+    )
+
+
+def operations(children: st.SearchStrategy[Expression]) -> st.SearchStrategy[Expression]:
+    """Combine the `children` with the arithmetic operators, evaluating the result."""
+
+    terms = st.lists(children, min_size=2, max_size=3)
+
+    return st.one_of(
+        terms.map(lambda args: reduce(lambda acc, x: acc + x, args)),
+        terms.map(lambda args: reduce(lambda acc, x: acc * x, args)),
+        st.tuples(children, positive_exponents).map(lambda args: args[0] ** args[1]),
+        children.map(sin),
+    )
+
+
+# Expressions of bounded size. Hypothesis shrinks failing examples towards the leaves, replacing
+# subtrees by their children and reducing the numerical values.
+raw_expressions = st.recursive(leaves, raw_operations, max_leaves=8)
+expressions = st.recursive(leaves, operations, max_leaves=8)
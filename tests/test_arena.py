@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+from qadence2_expressions import (
+    ExprArena,
+    Expression,
+    Support,
+    symbol,
+    value,
+)
+
+
+def test_shared_subexpressions() -> None:
+    arena = ExprArena()
+    x = symbol("x")
+
+    lhs = arena.build(Expression.Tag.ADD, x, value(1))
+    rhs = arena.intern(Expression.add(symbol("x"), value(1)))
+    assert lhs is rhs
+    assert lhs == x + 1
+
+    # The subexpressions of larger trees are shared too.
+    expr = arena.build(Expression.Tag.MUL, Expression.add(x, value(1)), symbol("y"))
+    assert expr[0] is lhs
+    assert arena.intern(x) is lhs[0]
+    assert len(arena) == 5
+
+    operator = Expression.quantum_operator(symbol("X"), Support(0), is_hermitian=True)
+    built = arena.build(Expression.Tag.QUANTUM_OP, symbol("X"), Support(0), is_hermitian=True)
+    assert arena.intern(operator) is built
+
+
+def test_arenas_are_independent() -> None:
+    first, second = ExprArena(), ExprArena()
+    expr = Expression.add(symbol("x"), value(1))
+
+    assert first.intern(expr) == second.intern(expr)
+    assert first.intern(expr) is not second.intern(expr)
+    assert expr in first and symbol("y") not in first
+
+
+def test_numeric_types_are_kept() -> None:
+    arena = ExprArena()
+    x = symbol("x")
+
+    values = [arena.intern(Expression(Expression.Tag.VALUE, number)) for number in (1, 1.0, 1 + 0j)]
+    assert [type(val[0]) for val in values] == [int, float, complex]
+    assert len(arena) == 3
+
+    # The expressions holding them are kept apart too.
+    real = arena.intern(Expression.add(x, Expression(Expression.Tag.VALUE, 1.0)))
+    imaginary = arena.intern(Expression.add(Expression(Expression.Tag.VALUE, 1 + 0j), x))
+    assert real == imaginary and real is not imaginary
+    assert arena.intern(Expression.add(x, Expression(Expression.Tag.VALUE, 1.0))) is real
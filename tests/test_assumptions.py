@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    Expression,
+    SymbolAssumptions,
+    evaluate,
+    exp,
+    log,
+    parameter,
+    simplify_with_assumptions,
+    value,
+)
+
+
+def test_sqrt_of_square() -> None:
+    x = parameter("x")
+    sqrt_x2 = Expression.pow(Expression.pow(x, value(2)), value(0.5))
+
+    assert simplify_with_assumptions(sqrt_x2, {"x": SymbolAssumptions(positive=True)}) == x
+    assert simplify_with_assumptions(sqrt_x2, {"x": SymbolAssumptions(real=True)}) == abs(x)
+
+    # Without assumptions, or with assumptions about other symbols, nothing is rewritten.
+    assert simplify_with_assumptions(sqrt_x2, {}) == sqrt_x2
+    assert simplify_with_assumptions(sqrt_x2, {"y": SymbolAssumptions(positive=True)}) == sqrt_x2
+
+
+def test_odd_root_of_odd_power() -> None:
+    x = parameter("x")
+    cbrt_x3 = Expression.pow(Expression.pow(x, value(3)), value(1 / 3))
+
+    # With the principal branch, `((-8)^3)^(1/3)` is complex, so real bases are not merged.
+    simplified = simplify_with_assumptions(cbrt_x3, {"x": SymbolAssumptions(real=True)})
+    assert evaluate(simplified, {"x": -8}) == pytest.approx(evaluate(cbrt_x3, {"x": -8}))
+    assert simplified == cbrt_x3
+
+    assert simplify_with_assumptions(cbrt_x3, {"x": SymbolAssumptions(positive=True)}) == x
+
+
+def test_assumption_guarded_rules() -> None:
+    x = parameter("x")
+    n = parameter("n")
+    positive = {"x": SymbolAssumptions(positive=True)}
+    real = {"x": SymbolAssumptions(real=True)}
+
+    assert simplify_with_assumptions(abs(x) + 1, positive) == x + 1
+    assert simplify_with_assumptions(abs(x) + 1, real) == abs(x) + 1
+
+    assert simplify_with_assumptions(log(exp(x)), real) == x
+    assert simplify_with_assumptions(log(exp(x)), {}) == log(exp(x))
+    assert simplify_with_assumptions(exp(log(x)), positive) == x
+    assert simplify_with_assumptions(exp(log(x)), real) == exp(log(x))
+
+    root_power = Expression.pow(Expression.pow(x, value(0.5)), n)
+    integer = {"n": SymbolAssumptions(integer=True)}
+    assert simplify_with_assumptions(root_power, integer) == x ** (0.5 * n)
+    assert simplify_with_assumptions(root_power, {}) == root_power
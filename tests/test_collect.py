@@ -1,10 +1,18 @@
 from __future__ import annotations
 
+import pytest
+
 from qadence2_expressions import (
     X,
     Y,
+    Z,
+    Expression,
+    as_linear_combination,
     collect_operators,
+    group_by_support,
+    noncommutative_multiplication,
     parameter,
+    symbol,
     value,
 )
 
@@ -23,3 +31,47 @@ def test_collect_multiple_operators() -> None:
         Y(): value(-1),
         X(0) * X(1): a * 0.5,
     }
+
+
+def test_linear_combination() -> None:
+    def is_pauli(op: Expression) -> bool:
+        return op.is_quantum_operator
+
+    assert as_linear_combination(2 * X(0) + 3 * Z(1), is_pauli) == [(2.0, X(0)), (3.0, Z(1))]
+    assert as_linear_combination(2 * (X(0) + Z(1)) - X(0), is_pauli) == [(1.0, X(0)), (2.0, Z(1))]
+
+    def is_pauli_string(op: Expression) -> bool:
+        return op.is_one or op.is_quantum_operator or op.is_kronecker_product
+
+    h = 1 + X(0) * X(1) - 0.5 * Z(0) * Z(1)
+    assert as_linear_combination(h, is_pauli_string) == [
+        (1.0, value(1)),
+        (1.0, X(0) * X(1)),
+        (-0.5, Z(0) * Z(1)),
+    ]
+
+    with pytest.raises(ValueError):
+        as_linear_combination(parameter("a") * X(0), is_pauli)
+
+    with pytest.raises(ValueError):
+        as_linear_combination(X(0) * X(1), is_pauli)
+
+    # Noncommutative products keep their order and attributes.
+    a, b = symbol("a"), symbol("b")
+    with noncommutative_multiplication():
+        ab, ba = a * b, b * a
+        h = 3 * ab + ba
+
+    assert as_linear_combination(h, lambda op: op.is_multiplication) == [(3.0, ab), (1.0, ba)]
+
+
+def test_group_by_support() -> None:
+    assert group_by_support(X(0) + Z(1) + Y(0)) == [[X(0), Z(1)], [Y(0)]]
+    assert group_by_support(X(0) * X(1) + Z(1) + Z(2) + 2 * Y(0)) == [
+        [2 * Y(0), Z(1), Z(2)],
+        [X(0) * X(1)],
+    ]
+
+    # Scalars fit in any group and operators on all the qubits are alone.
+    assert group_by_support(X() + Z(1) + 3) == [[3, X()], [Z(1)]]
+    assert group_by_support(X(0)) == [[X(0)]]
@@ -1,17 +1,26 @@
 from __future__ import annotations
 
+import math
+
 import pytest
 
 from qadence2_expressions import (
+    EvalContext,
     Expression,
+    Product,
+    Sum,
     Support,
     array_parameter,
     array_variable,
+    evaluate,
     function,
     parameter,
     parametric_operator,
+    parse,
     projector,
+    promote,
     symbol,
+    symbols,
     unitary_hermitian_operator,
     value,
     variable,
@@ -53,3 +62,57 @@ def test_constructor() -> None:
     assert parametric_operator("RX", 3.14)(1) == Expression(
         Expression.Tag.QUANTUM_OP, Expression.function("RX", 3.14), Support(1), join=None
     )
+
+
+def test_symbols() -> None:
+    x, y = symbols("x y")
+    assert (x, y) == (Expression.symbol("x"), Expression.symbol("y"))
+
+    expr = 2 * x + y
+    assert expr == Expression.add(Expression.mul(value(2), Expression.symbol("x")), y)
+    assert expr.args == (Expression.mul(value(2), x), y)
+
+    a, b, c = symbols("a, b,c", trainable=True)
+    assert c == variable("c")
+
+    with pytest.raises(SyntaxError):
+        symbols("x E")
+
+
+def test_keyword_arguments() -> None:
+    t, sigma = symbols("t sigma")
+
+    expr = function("gaussian", t, mean=0, sigma=sigma)
+    assert expr == Expression(
+        Expression.Tag.FN,
+        Expression.symbol("gaussian"),
+        t,
+        value(0),
+        sigma,
+        keywords=("mean", "sigma"),
+    )
+    assert expr != function("gaussian", t, value(0), sigma)
+    assert str(expr) == "gaussian(t,\u2009mean=0.0,\u2009sigma=sigma)"
+    assert str(function("f", mean=0)) == "f(mean=0.0)"
+    assert parse("gaussian(t, mean=0, sigma=sigma)") == expr
+
+    def gaussian(t: float, mean: float, sigma: float) -> float:
+        return math.exp(-((t - mean) ** 2) / (2 * sigma**2))
+
+    context = EvalContext()
+    context.register("gaussian", gaussian)
+    assert evaluate(expr, {"t": 1, "sigma": 2}, context) == gaussian(1, mean=0, sigma=2)
+
+
+def test_sum_and_product() -> None:
+    x, y, z = symbols("x y z")
+    X = unitary_hermitian_operator("X")
+
+    assert promote(Sum([x, 2 * y, 1, x])) == 1 + 2 * x + 2 * y
+    assert promote(Product([2, x, y**2, x])) == 2 * x**2 * y**2
+    assert promote(Sum([Product([x, y]), Product([y, x]), z])) == 2 * x * y + z
+    assert promote(Product([x, X(1), X(0)])) == x * X(1) * X(0)
+
+    # The empty sum and product are the neutral elements.
+    assert promote(Sum([])) == value(0)
+    assert promote(Product([])) == value(1)
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+from math import sqrt
+
+import pytest
+
+from qadence2_expressions import (
+    CZ,
+    H,
+    X,
+    Z,
+    decompose,
+    unitary_hermitian_operator,
+)
+
+HADAMARD = (X(0) + Z(0)) / sqrt(2)
+
+
+def test_decompose() -> None:
+    library = {"H": HADAMARD}
+
+    assert decompose(H(3), library) == (X(3) + Z(3)) / sqrt(2)
+    assert decompose(H(0) * Z(1), library) == (X(0) + Z(0)) * Z(1) / sqrt(2)
+    assert decompose(H(1).dag, library) == (X(1) + Z(1)) / sqrt(2)
+    assert decompose(X(0), library) == X(0)
+
+
+def test_decompose_nested_definitions() -> None:
+    CNOT = unitary_hermitian_operator("CNOT")
+    library = {"H": HADAMARD, "CNOT": H(1) * CZ(target=(1,), control=(0,)) * H(1)}
+
+    h5 = (X(5) + Z(5)) / sqrt(2)
+    assert decompose(CNOT(2, 5), library) == h5 * CZ(target=(5,), control=(2,)) * h5
+
+
+def test_decompose_errors() -> None:
+    A = unitary_hermitian_operator("A")
+    B = unitary_hermitian_operator("B")
+
+    with pytest.raises(ValueError, match="cyclic"):
+        decompose(A(0), {"A": B(0), "B": A(0)})
+
+    with pytest.raises(ValueError):
+        decompose(H(0), {"H": X(0) * X(1)})
+
+    with pytest.raises(ValueError):
+        decompose(H(target=(1,), control=(0,)), {"H": HADAMARD})
@@ -0,0 +1,100 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+from qadence2_expressions import (
+    RX,
+    X,
+    Y,
+    cos,
+    derivative,
+    evaluate,
+    exp,
+    function,
+    linearize_around,
+    log,
+    parameter,
+    parameter_shift,
+    sin,
+    sqrt,
+    symbol,
+    value,
+    variable,
+)
+
+x = parameter("x")
+y = parameter("y")
+
+
+def test_derivative_polynomials() -> None:
+    assert derivative(value(3), "x") == value(0)
+    assert derivative(y, "x") == value(0)
+    assert derivative(x, "x") == value(1)
+    assert derivative(3 * x**2 + 2 * x * y + 5, "x") == 6 * x + 2 * y
+    assert derivative(1 / x, "x") == -(x**-2)
+    assert derivative(sqrt(x), "x") == 0.5 * x**-0.5
+
+
+def test_derivative_chain_rule() -> None:
+    assert derivative(sin(x**2), "x") == cos(x**2) * 2 * x
+    assert derivative(cos(2 * x), "x") == -2 * sin(2 * x)
+    assert derivative(exp(x * y), "x") == y * exp(x * y)
+    assert derivative(log(cos(x)), "x") == -sin(x) / cos(x)
+    assert derivative(x**x, "x") == x**x * log(x) + x**x
+
+
+def test_derivative_of_operators() -> None:
+    assert derivative(x * X(0) + y * Y(1), "x") == X(0)
+    assert derivative(x**2 * X(0) * Y(1), "x") == 2 * x * X(0) * Y(1)
+
+
+def test_deferred_derivative() -> None:
+    f = function("f", x)
+    diff = function("diff", f, symbol("x"))
+
+    assert derivative(f, "x") == diff
+    assert derivative(f * x, "x") == diff * x + f
+    assert derivative(function("f", y), "x") == value(0)
+
+
+def test_parameter_shift() -> None:
+    theta = variable("theta")
+
+    plus, minus = parameter_shift(cos(theta) * x, "theta")
+    assert plus == cos(theta + math.pi / 2) * x
+    assert minus == cos(theta - math.pi / 2) * x
+
+    # The parameter-shift rule recovers the derivative of the expression.
+    values = {"theta": 0.3, "x": 2.0}
+    gradient = (evaluate(plus, values) - evaluate(minus, values)) / 2
+    assert gradient == pytest.approx(evaluate(derivative(cos(theta) * x, "theta"), values))
+
+    plus, minus = parameter_shift(RX(theta)(0), "theta", 0.5)
+    assert plus == RX(theta + 0.5)(0)
+    assert minus == RX(theta - 0.5)(0)
+    assert plus[0][1][1].get("trainable")
+
+    assert parameter_shift(x * y, "theta") == (x * y, x * y)
+
+
+def test_linearize_around() -> None:
+    assert linearize_around(x**2, {"x": 1}) == 1 + 2 * (x - 1)
+
+    expr = x * sin(y) + y**2
+    point = {"x": 2, "y": 0.5}
+    linear = linearize_around(expr, point)
+    assert linear.is_addition
+    assert evaluate(linear, point) == pytest.approx(evaluate(expr, point))
+    assert evaluate(derivative(linear, "x"), point) == pytest.approx(math.sin(0.5))
+    assert evaluate(derivative(linear, "y"), point) == pytest.approx(2 * math.cos(0.5) + 1)
+
+    # Constant directions are dropped and the attributes of the symbols are preserved.
+    theta = variable("theta")
+    assert linearize_around(x * theta, {"x": 0, "theta": 1}) == x
+    assert linearize_around(theta**3, {"theta": 0}) == value(0)
+    assert linearize_around(theta**2, {"theta": 1})[1][1].get("trainable")
+
+    with pytest.raises(ValueError):
+        linearize_around(x * y, {"x": 1})
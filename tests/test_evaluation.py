@@ -0,0 +1,305 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+from qadence2_expressions import (
+    RX,
+    X,
+    EvalContext,
+    Expression,
+    compile_closure,
+    compile_expression,
+    cos,
+    eval_cost,
+    evaluate,
+    evaluate_over_time,
+    evaluate_vec,
+    exp,
+    function,
+    parameter,
+    parameters,
+    partial_evaluate,
+    probably_equal,
+    sin,
+    sqrt,
+    substitute_value,
+    value,
+)
+from qadence2_expressions.evaluation import FUNCTION_COST
+
+x = parameter("x")
+y = parameter("y")
+theta = parameter("theta")
+
+
+def test_evaluate() -> None:
+    assert evaluate(2 * x + y, {"x": 1, "y": 3}) == 5
+    assert evaluate(sin(x) ** 2 + cos(x) ** 2, {"x": 0.3}) == pytest.approx(1)
+    assert evaluate(exp(x), {"x": 1}) == pytest.approx(math.e)
+    assert evaluate(sqrt(x), {"x": -4}) == pytest.approx(2j)
+
+
+def test_evaluate_errors() -> None:
+    with pytest.raises(ValueError):
+        evaluate(x + y, {"x": 1})
+
+    with pytest.raises(ValueError):
+        evaluate(x * X(0), {"x": 1})
+
+    with pytest.raises(ValueError, match="undefined"):
+        evaluate(x + value(0) / 0, {"x": 1})
+
+    with pytest.raises(ValueError, match="undefined"):
+        compile_expression(Expression.undefined())
+
+    # The quotient `0 / 0` of evaluated values is undefined, other divisions by zero are errors.
+    with pytest.raises(ValueError, match="undefined"):
+        evaluate(x / y, {"x": 0, "y": 0})
+
+    with pytest.raises(ZeroDivisionError):
+        evaluate(x / y, {"x": 1, "y": 0})
+
+
+def test_numeric_function_arguments() -> None:
+    # Functions can hold raw numbers as arguments, see `Expression.function`.
+    expr = Expression.function("sin", 1.57)
+    expected = math.sin(1.57)
+
+    assert evaluate(expr) == pytest.approx(expected)
+    assert compile_expression(expr)([]) == pytest.approx(expected)
+    assert compile_closure(expr, [])([]) == pytest.approx(expected)
+    assert eval_cost(expr) == FUNCTION_COST
+    assert partial_evaluate(expr * x, {"x": 2}) == value(2 * expected)
+    assert substitute_value(expr * x, "x", 2) == value(2 * expected)
+
+
+def test_parameters() -> None:
+    expr = theta * x + RX(y)(0) + exp(x)
+    assert parameters(expr) == ["theta", "x", "y"]
+    assert parameters(x * y) == parameters(y * x)
+
+
+def test_evaluate_vec() -> None:
+    expr = theta * y + x**2
+
+    assert evaluate_vec(expr, [1, 2, 3]) == evaluate(expr, {"theta": 1, "x": 2, "y": 3})
+    assert evaluate_vec(expr, [1, 2, 3]) == 7
+
+    with pytest.raises(ValueError):
+        evaluate_vec(expr, [1, 2])
+
+
+def test_custom_functions() -> None:
+    context = EvalContext()
+    context.register("double", lambda x: 2 * x)
+
+    assert evaluate(function("double", value(5)), context=context) == 10
+    assert evaluate(function("double", x) + sin(x), {"x": 0}, context) == 0
+
+    with pytest.raises(ValueError):
+        evaluate(function("double", value(5)))
+
+    # User-defined functions take precedence over the built-in ones.
+    context.register("sin", lambda x: 42)
+    assert evaluate(sin(x), {"x": 0}, context) == 42
+
+
+def test_compiled_expression() -> None:
+    context = EvalContext()
+    context.register("double", lambda x: 2 * x)
+    context.register("two", lambda: 2.0)
+
+    exprs = [
+        theta * y + x**2,
+        sin(x) ** 2 + cos(theta * y),
+        exp(-(x**2) / 2) / sqrt(theta),
+        function("double", x + y) * 3 - 1,
+        value(4),
+        x + function("two"),
+        function("two"),
+    ]
+
+    for expr in exprs:
+        compiled = compile_expression(expr, context)
+        params = [0.5, 1.5, -2.0][: len(compiled.parameters)]
+        expected = evaluate(expr, dict(zip(compiled.parameters, params)), context)
+        assert compiled(params) == pytest.approx(expected)
+
+    with pytest.raises(ValueError):
+        compile_expression(theta * y)([1])
+
+
+def test_partial_evaluate() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    expr = partial_evaluate(x * y + 3, {"x": 2})
+    assert expr == 2 * y + 3
+    assert parameters(expr) == ["y"]
+
+    expr = partial_evaluate(x * y + sin(x) + cos(y), {"x": 0.5})
+    assert expr == value(math.sin(0.5)) + 0.5 * y + cos(y)
+    assert evaluate(expr, {"y": 1}) == pytest.approx(math.sin(0.5) + 0.5 + math.cos(1))
+
+    expr = partial_evaluate(exp(x) * X(0), {"x": 0})
+    assert expr == X(0)
+
+
+def test_partial_evaluate_unknown_functions() -> None:
+    x = parameter("x")
+    expr = function("f", x) + x
+
+    assert partial_evaluate(expr, {"x": 1}) == function("f", value(1)) + 1
+
+    context = EvalContext()
+    context.register("f", lambda x: 2 * x)
+    assert partial_evaluate(expr, {"x": 1}, context) == value(3)
+
+
+def test_single_precision() -> None:
+    x = parameter("x")
+    y = parameter("y")
+    single = EvalContext(precision="single")
+
+    assert evaluate(value(0.1), context=single) == 0.10000000149011612
+    assert evaluate(value(0.1j), context=single) == 0.10000000149011612j
+    assert evaluate(x + y, {"x": 1, "y": 1e-8}) == 1.00000001
+    assert evaluate(x + y, {"x": 1, "y": 1e-8}, single) == 1.0
+
+    expr = sin(x) * y + exp(x)
+    values = {"x": 0.3, "y": 1.7}
+    result = evaluate(expr, values, single)
+    assert result != evaluate(expr, values)
+    assert result == pytest.approx(evaluate(expr, values), rel=1e-6)
+
+    compiled = compile_expression(expr, single)
+    assert compiled([0.3, 1.7]) == result
+    assert compile_closure(expr, ["x", "y"], single)([0.3, 1.7]) == result
+    assert compile_closure(expr, ["x", "y"])([0.3, 1.7]) != result
+
+
+def test_compile_closure() -> None:
+    x = parameter("x")
+    y = parameter("y")
+    theta = parameter("theta")
+
+    expr = theta * x**2 + sin(x * y) / 3 - exp(-y) + sqrt(theta)
+    params = ["x", "y", "theta"]
+    fn = compile_closure(expr, params)
+
+    for xs in [(0.0, 1.0, 2.0), (1.5, -0.3, 0.7), (-2.0, 4.0, 10.0)]:
+        assert fn(xs) == pytest.approx(evaluate(expr, dict(zip(params, xs))))
+
+
+def test_compile_closure_errors() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    with pytest.raises(ValueError):
+        compile_closure(1j * x, ["x"])
+
+    with pytest.raises(ValueError):
+        compile_closure(x + y, ["x"])
+
+    with pytest.raises(ValueError):
+        compile_closure(x * X(0), ["x"])
+
+    with pytest.raises(ValueError):
+        compile_closure(sqrt(x), ["x"])([-1.0])
+
+    context = EvalContext()
+    context.register("f", lambda x: x + 1)
+    assert compile_closure(function("f", x), ["x"], context)([1.0]) == 2.0
+
+
+def test_constants() -> None:
+    pi = parameter("pi")
+    x = parameter("x")
+
+    assert evaluate(2 * pi) == pytest.approx(2 * math.pi)
+    assert evaluate(exp(parameter("I") * pi)) == pytest.approx(-1)
+    assert evaluate(2 * pi, {"pi": 3}) == 6
+    assert parameters(2 * pi * x) == ["x"]
+    assert evaluate_vec(2 * pi * x, [1]) == pytest.approx(2 * math.pi)
+    assert compile_expression(pi * x)([2]) == pytest.approx(2 * math.pi)
+
+
+def test_constants_per_context() -> None:
+    tau = parameter("tau")
+    context = EvalContext()
+    context.constants["tau"] = 2 * math.pi
+    del context.constants["pi"]
+
+    assert evaluate(tau, context=context) == pytest.approx(2 * math.pi)
+    assert parameters(parameter("pi") * tau, context) == ["pi"]
+
+    with pytest.raises(ValueError):
+        evaluate(tau)
+    assert "tau" not in EvalContext().constants
+    assert evaluate(parameter("pi")) == math.pi
+
+
+def test_eval_cost() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    assert eval_cost(value(2)) == 0
+    assert eval_cost(x) == 0
+    assert eval_cost(x**8 + y) == 5
+    assert eval_cost(x**7) == 3
+    assert eval_cost(x**-2) == 2
+    assert eval_cost(2 * x * y + 1) == 3
+    assert eval_cost(sin(x) + sqrt(y)) == 2 * FUNCTION_COST + 1
+    assert eval_cost(x**y) == FUNCTION_COST
+
+    with pytest.raises(ValueError):
+        eval_cost(x * X(0))
+
+
+def test_evaluate_over_time() -> None:
+    t = parameter("t")
+    expr = theta * sin(x * t) + exp(-t) * cos(x)
+    times = [i * 0.1 for i in range(50)]
+    values = {"theta": 1.5, "x": 0.7}
+
+    result = evaluate_over_time(expr, "t", times, values)
+    expected = [evaluate(expr, {**values, "t": time}) for time in times]
+    assert result == pytest.approx(expected)
+
+    # The time symbol is ignored in the values, and constant expressions are repeated.
+    assert evaluate_over_time(theta * t, "t", [1, 2], {"theta": 2, "t": 5}) == [2.0, 4.0]
+    assert evaluate_over_time(theta, "t", [1, 2, 3], {"theta": 2}) == [2.0, 2.0, 2.0]
+
+    with pytest.raises(ValueError, match="theta"):
+        evaluate_over_time(theta * t, "t", times)
+
+
+def test_substitute_value() -> None:
+    result = substitute_value(x**2 + 1, "x", 3)
+    assert result.is_value
+    assert result == value(10)
+
+    assert substitute_value(x**2 + y, "x", 3) == 9 + y
+    assert substitute_value(sin(x) * y, "x", 0) == value(0)
+    assert substitute_value(function("f", x) + y, "x", 2) == function("f", value(2)) + y
+    assert substitute_value(RX(x)(0), "x", 0.5) == RX(0.5)(0)
+    assert substitute_value(x * y, "theta", 1) == x * y
+
+    # Agrees with the general partial evaluation.
+    expr = theta * sin(x) + x**2 * y
+    assert substitute_value(expr, "x", 0.5) == partial_evaluate(expr, {"x": 0.5})
+
+
+def test_probably_equal() -> None:
+    assert probably_equal((x + 1) ** 2, x**2 + 2 * x + 1)
+    assert probably_equal(sin(x) ** 2 + cos(x) ** 2, value(1))
+    assert probably_equal(x * y, y * x, samples=4, seed=7)
+
+    assert not probably_equal(x**2, x**3)
+    assert not probably_equal(x, y)
+    assert not probably_equal(sin(x), sin(x) + 1e-3)
+
+    with pytest.raises(ValueError):
+        probably_equal(X(0), X(0))
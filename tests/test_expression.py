@@ -1,10 +1,22 @@
 from __future__ import annotations
 
+import cmath
+import pickle
+from dataclasses import dataclass, field
+from functools import reduce
+from typing import Any
+
 import pytest
 
 from qadence2_expressions import (
     Expression,
+    FormatOptions,
     Support,
+    checked_pow,
+    function,
+    noncommutative_multiplication,
+    numeric_sort_key,
+    parametric_operator,
     symbol,
     unitary_hermitian_operator,
     value,
@@ -39,6 +51,19 @@ def test_addition() -> None:
     assert X() + 2 + a == Expression.add(value(2), a, X())
 
 
+def test_subtraction_cancellation() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    X = unitary_hermitian_operator("X")
+
+    assert a - a == value(0)
+    assert (a + b) - (a + b) == value(0)
+    assert ((a + b) - (a + b)).is_value
+    assert (a + b + 1) - (a + b) == value(1)
+    assert (a + b) - a == b
+    assert (a * X(0) + X(1)) - (a * X(0) + X(1)) == value(0)
+
+
 def test_negation() -> None:
     a = symbol("a")
     X = unitary_hermitian_operator("X")
@@ -157,3 +182,626 @@ def test_subspace_propagation() -> None:
     term2 = Expression.mul(b, X(2))
     expr = Expression.add(term1, term2)
     assert expr.subspace == Support(1, 2)
+
+
+def test_identity_and_absorbing_elements() -> None:
+    assert Expression.Tag.ADD.identity() == value(0)
+    assert Expression.Tag.ADD.absorbing() is None
+
+    assert Expression.Tag.MUL.identity() == value(1)
+    assert Expression.Tag.MUL.absorbing() == value(0)
+
+    assert Expression.Tag.POW.identity() is None
+    assert Expression.Tag.POW.absorbing() is None
+
+
+def test_sort_key() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    X = unitary_hermitian_operator("X")
+
+    exprs = [a * b, X(1), a + 1, value(2), b, X(0), value(-1), a]
+    expected = [value(-1), value(2), a, b, X(0), X(1), a + 1, a * b]
+
+    assert sorted(exprs, key=Expression.sort_key) == expected
+    assert sorted(exprs[::-1], key=Expression.sort_key) == expected
+
+    # The order of the terms in commutative operations does not change the key.
+    assert Expression.add(a, b).sort_key() == Expression.add(b, a).sort_key()
+
+
+def test_sort_key_numeric_function_arguments() -> None:
+    a = symbol("a")
+
+    exprs = [function("f", 2.5), function("f", a), function("f", 1.5)]
+    expected = [function("f", 1.5), function("f", 2.5), function("f", a)]
+
+    assert sorted(exprs, key=Expression.sort_key) == expected
+
+
+def test_numeric_sort_key() -> None:
+    nan = float("nan")
+    numbers = [2, 1j, nan, -1 + 5j, 1 - 1j, complex(0, nan), -3.5]
+
+    result = sorted(numbers, key=numeric_sort_key)
+    assert result[:3] == [-3.5, -1 + 5j, 1j]
+    assert result[3].real == 0 and cmath.isnan(result[3].imag)
+    assert result[4:6] == [1 - 1j, 2]
+    assert cmath.isnan(result[6])
+
+    # NaN values are not equal to themselves, so the orders are compared through their keys.
+    reversed_result = sorted(numbers[::-1], key=numeric_sort_key)
+    assert list(map(numeric_sort_key, reversed_result)) == list(map(numeric_sort_key, result))
+
+    exprs = sorted([value(x) for x in numbers], key=Expression.sort_key)
+    assert [expr.sort_key() for expr in exprs] == [value(x).sort_key() for x in result]
+
+
+def test_checked_pow() -> None:
+    assert checked_pow(2, 3) == 8
+    assert checked_pow(2.0, -1) == 0.5
+    assert checked_pow(0, 0) == 1
+    assert checked_pow(0.0, 2.5) == 0
+    assert checked_pow(-4.0, 0.5) == pytest.approx(2j)
+
+    with pytest.raises(ZeroDivisionError):
+        value(0) ** -1
+
+    for base, exponent in [
+        (0, -1),
+        (0.0, -0.5),
+        (0j, 1j),
+        (10.0, 400),
+        (float("inf"), 2),
+        (2, float("nan")),
+    ]:
+        with pytest.raises(ValueError):
+            checked_pow(base, exponent)
+
+
+def test_undefined() -> None:
+    a = symbol("a")
+    X = unitary_hermitian_operator("X")
+    inf = float("inf")
+    undefined = Expression.undefined()
+
+    assert (value(0) / value(0)).is_undefined
+    assert (value(inf) - value(inf)).is_undefined
+    assert (value(0) * value(inf)).is_undefined
+    assert (value(inf) / -inf).is_undefined
+    assert str(undefined) == "undefined"
+    assert undefined.free_symbols == set()
+
+    # Undefined values propagate through any operation.
+    assert (a + value(0) / 0).is_undefined
+    assert (0 * undefined).is_undefined
+    assert (undefined**0).is_undefined
+    assert (X(0) * undefined).is_undefined
+    assert function("f", a, undefined).is_undefined
+    assert abs(undefined) == undefined
+    assert (undefined // 2).is_undefined
+
+    # The explicit NaN values and the other infinities are kept.
+    assert (value(float("nan")) + 1).is_nan
+    assert value(1) / inf == value(0)
+    assert (value(inf) + 1) == value(inf)
+
+    # The undefined value is marked by an attribute, so the name remains available.
+    assert not symbol("undefined").is_undefined
+    assert symbol("undefined") != undefined
+    assert symbol("undefined").free_symbols == {"undefined"}
+
+
+def test_nan_equality() -> None:
+    a = symbol("a")
+    nan = value(float("nan"))
+
+    assert nan.is_nan
+    assert not nan.is_finite
+    assert not value(float("inf")).is_finite
+    assert not value(complex(1, float("inf"))).is_finite
+    assert value(1j).is_finite
+    assert not a.is_nan and not a.is_finite
+
+    # IEEE 754 semantics.
+    assert nan != value(float("nan"))
+    assert a + nan != a + value(float("nan"))
+
+    # Total equality.
+    assert nan.total_eq(value(float("nan")))
+    assert (a + nan).total_eq(value(float("nan")) + a)
+    assert not nan.total_eq(value(1))
+    assert hash(nan) == hash(value(float("nan")))
+
+
+def test_checked_constructors() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    X = unitary_hermitian_operator("X")
+
+    assert Expression.add_node(a, b) == Expression.add(a, b)
+    assert Expression.mul_node(a, b, value(2)) == Expression.mul(a, b, value(2))
+    assert Expression.kron_node(X(0), X(1)) == Expression.kron(X(0), X(1))
+    assert Expression.pow_node(a, value(2)) == Expression.pow(a, value(2))
+    assert Expression.function_node("f", a, b) == Expression.function("f", a, b)
+
+    with pytest.raises(SyntaxError):
+        Expression.add_node(a)
+
+    with pytest.raises(SyntaxError):
+        Expression.mul_node()
+
+    with pytest.raises(SyntaxError):
+        Expression.kron_node(X(0), a)
+
+    with pytest.raises(SyntaxError):
+        Expression.function_node("not a name", a)
+
+    with pytest.raises(TypeError):
+        Expression.pow_node(a, 2)  # type: ignore [arg-type]
+
+
+def test_from_op() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    c = symbol("c")
+    X = unitary_hermitian_operator("X")
+
+    expr = Expression.from_op(Expression.Tag.ADD, [a, b, c])
+    assert expr == Expression.add(a, b, c)
+    assert len(expr.args) == 3
+
+    assert Expression.from_op(Expression.Tag.MUL, (a, b)) == Expression.mul(a, b)
+    assert Expression.from_op(Expression.Tag.KRON, [X(0), X(1)]) == Expression.kron(X(0), X(1))
+    assert Expression.from_op(Expression.Tag.POW, [a, value(2)]) == Expression.pow(a, value(2))
+
+    with pytest.raises(SyntaxError):
+        Expression.from_op(Expression.Tag.ADD, [a])
+
+    with pytest.raises(SyntaxError):
+        Expression.from_op(Expression.Tag.POW, [a, b, c])
+
+    with pytest.raises(TypeError):
+        Expression.from_op(Expression.Tag.MUL, [a, 2])  # type: ignore [list-item]
+
+    with pytest.raises(ValueError):
+        Expression.from_op(Expression.Tag.SYMBOL, [a])
+
+
+def test_reciprocal_cancellation() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    A = Expression.quantum_operator(Expression.symbol("A"), Support(0))
+    B = Expression.quantum_operator(Expression.symbol("B"), Support(0))
+
+    assert a * a**-1 == value(1)
+    assert a**2 * a**-1 == a
+    assert (2 * a * b) * (2 * a) ** -1 == b
+
+    # Operators only cancel when they are next to each other.
+    assert A * A**-1 == value(1)
+    assert A**2 * A**-1 == A
+    assert A * B * A**-1 == Expression.kron(A, B, A**-1)
+
+
+def test_free_symbols_with_counts() -> None:
+    x = symbol("x")
+    y = symbol("y")
+    f = Expression.function("f", x)
+
+    # Built without evaluation, otherwise `x * x` becomes `x^2`.
+    expr = Expression.add(Expression.mul(x, x), Expression.mul(x, y))
+    assert expr.free_symbols_with_counts() == {"x": 3, "y": 1}
+    assert (x * x + x * y).free_symbols_with_counts() == {"x": 2, "y": 1}
+
+    # Function names are not counted.
+    assert (f * y).free_symbols_with_counts() == {"x": 1, "y": 1}
+    assert (f * y).free_symbols == {"x", "y"}
+
+
+def test_noncommutative_multiplication() -> None:
+    a = symbol("a")
+    b = symbol("b")
+
+    assert a * b == b * a
+    assert a * b - b * a == value(0)
+
+    with noncommutative_multiplication():
+        ab = a * b
+        ba = b * a
+        assert ab == Expression(Expression.Tag.MUL, a, b, commutative=False)
+        assert ab.args == (a, b)
+        assert ab != ba
+        assert 2 * a * b * 3 == Expression(Expression.Tag.MUL, value(6), a, b, commutative=False)
+        assert ab - ba == Expression.add(ab, -ba)
+        assert ab - a * b == value(0)
+
+    # The default behaviour is restored outside the context.
+    assert a * b - b * a == value(0)
+
+
+def test_noncommutative_power() -> None:
+    a = symbol("a")
+    b = symbol("b")
+    c = symbol("c")
+
+    with noncommutative_multiplication():
+        ab = a * b
+        assert ab**2 == Expression.pow(ab, value(2))
+        assert ab**2 != a**2 * b**2
+
+    # The products keep their order when they are extended outside of the context.
+    assert not (ab * c).is_commutative
+    assert (ab * c).args == (a, b, c)
+    assert (c * ab).args == (c, a, b)
+    assert ab**2 == Expression.pow(ab, value(2))
+
+
+def test_subexpressions() -> None:
+    x = symbol("x")
+    term = x + 1
+    expr = Expression.mul(term, term)
+
+    assert expr.node_count == 7
+    assert expr.subexpressions() == [value(1), x, term, expr]
+    assert expr.subexpressions(min_nodes=2) == [term, expr]
+
+    # Function names are not subexpressions.
+    f = Expression.function("f", term)
+    assert f.subexpressions() == [value(1), x, term, f]
+
+
+def test_generic_arithmetic() -> None:
+    a = symbol("a")
+
+    def total(items: list) -> Any:
+        return sum(items[1:], start=items[0])
+
+    # Expressions and numerical values can be mixed in generic arithmetic routines.
+    assert total([value(1), 2, 3.5]) == value(6.5)
+    assert total([1, a, value(2), a]) == 3 + 2 * a
+    assert sum([a, a, 1]) == 1 + 2 * a
+
+
+def test_tag_string_conversion() -> None:
+    Tag = Expression.Tag
+
+    assert f"{Tag.ADD} {Tag.MUL} {Tag.KRON} {Tag.POW} {Tag.FN}" == "+ * @ ^ call"
+    assert str(Tag.SYMBOL) == "Symbol"
+
+    for tag in Tag:
+        assert Tag.from_str(str(tag)) == tag
+
+    assert Tag.from_str("Power") == Tag.POW
+
+    with pytest.raises(ValueError):
+        Tag.from_str("%")
+
+
+def test_commutative_equality() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert x + y == y + x
+    assert x * y == y * x
+    assert Expression.add(x, y) == Expression.add(y, x)
+    assert Expression.pow(x, y) != Expression.pow(y, x)
+
+    # Repeated terms are taken into account.
+    assert Expression.add(x, x, y) != Expression.add(x, y)
+    assert Expression.mul(x, x, y) != Expression.mul(x, y, y)
+
+
+def test_equality_with_numbers() -> None:
+    x = symbol("x")
+
+    assert value(3) == 3
+    assert value(3) == 3.0
+    assert value(2j) == 2j
+    assert 3 == value(3)
+    assert value(3) != 4
+    assert x != 3
+    assert x + 1 != 1
+    assert value(1) != True  # noqa: E712
+
+    assert hash(value(3)) == hash(3)
+    assert {value(3): "three"}[3] == "three"
+
+
+def test_memory_usage() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert x.memory_usage() > 0
+    assert (x + y).memory_usage() > x.memory_usage() + y.memory_usage()
+
+    X = unitary_hermitian_operator("X")
+    Y = unitary_hermitian_operator("Y")
+    assert (X(0) * Y(1)).memory_usage() > X(0).memory_usage()
+
+    # Shared subexpressions are counted once.
+    term = Expression.function("sin", x + y)
+    shared = Expression.add(term, Expression.mul(symbol("a"), term))
+    copied = Expression.add(
+        Expression.function("sin", x + y),
+        Expression.mul(symbol("a"), Expression.function("sin", x + y)),
+    )
+    assert shared.memory_usage() < copied.memory_usage()
+
+
+def test_negative_exponents_display() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert str(-y / x) == "-y\u2009*\u2009x\u2009^\u2009-1.0"
+
+
+def test_subtraction_display() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert str(x - y) == "x - y"
+    assert str(-x + y) == "-x + y"
+    assert str(-x - y) == "-x - y"
+    assert str(x - 3) == "-3.0 + x"
+    assert str(x - 2 * y) == "x - 2.0\u2009*\u2009y"
+    assert str(Expression.add(x, Expression.mul(value(-1), y))) == "x - y"
+    assert str(Expression.add(x, value(-1))) == "x - 1.0"
+    assert str(Expression.add(x, Expression.mul(value(-1), x + y))) == "x - (x + y)"
+
+
+def test_leaves_and_operators() -> None:
+    x = symbol("x")
+    y = symbol("y")
+    X = unitary_hermitian_operator("X")
+
+    expr = x**2 + 1
+    assert list(expr.leaves()) == [value(1), x, value(2)]
+    assert list(expr.operators()) == [(Expression.Tag.ADD, 2), (Expression.Tag.POW, 2)]
+
+    # Function and operator names are skipped.
+    expr = Expression.function("f", x, y) * X(0)
+    assert list(expr.leaves()) == [x, y]
+    assert [head for head, _ in expr.operators()] == [
+        Expression.Tag.MUL,
+        Expression.Tag.FN,
+        Expression.Tag.QUANTUM_OP,
+    ]
+
+    # Deep expressions don't exceed the recursion limit.
+    deep = x
+    for _ in range(5000):
+        deep = Expression.add(deep, value(1))
+    assert sum(1 for _ in deep.leaves()) == 5001
+    assert sum(1 for _ in deep.operators()) == 5000
+
+
+def test_powers_of_imaginary_unit() -> None:
+    i = value(1j)
+    x = symbol("x")
+
+    assert i**2 == value(-1) and not isinstance((i**2)[0], complex)
+    assert i**3 == value(-1j)
+    assert i**4 == value(1) and not isinstance((i**4)[0], complex)
+    assert i**5 == i
+    assert i**101 == i
+    assert i**-1 == value(-1j)
+    assert value(-1j) ** 3 == i
+
+    assert i * i == value(-1)
+    assert i * i * i * i == value(1)
+    assert str(i**3) == "-1j"
+    assert (i * x) ** 2 == -(x**2)
+
+
+def test_zero_as_default() -> None:
+    @dataclass
+    class Term:
+        coefficient: Expression = field(default_factory=Expression.zero)
+
+    term = Term()
+    assert term.coefficient == value(0)
+    assert term.coefficient.is_zero
+    assert Term().coefficient is not term.coefficient
+
+
+def test_multiplication_styles() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    def render(expr: Expression, style: Any) -> str:
+        return expr.to_string(FormatOptions(mul_style=style))
+
+    assert render(2 * x, "asterisk") == str(2 * x) == "2.0\u2009*\u2009x"
+    assert render(2 * x, "dot") == "2.0\u2009\u00b7\u2009x"
+    assert render(2 * x, "implicit") == "2.0x"
+
+    # The operator is only omitted between a real coefficient and a symbol.
+    assert render(2 * x * y, "implicit") == "2.0x\u2009*\u2009y"
+    assert render(1j * x, "implicit") == "1j\u2009*\u2009x"
+    assert render(2 * x**2, "implicit") == "2.0\u2009*\u2009x\u2009^\u20092.0"
+    assert render(1 - 2 * x, "implicit") == "1.0 - 2.0x"
+    assert render(-x * y, "dot") == "-x\u2009\u00b7\u2009y"
+
+    # Juxtapositions that read as a single number keep the operator.
+    assert render(2 * symbol("e5"), "implicit") == "2.0\u2009*\u2009e5"
+    assert render(3 * symbol("j"), "implicit") == "3.0\u2009*\u2009j"
+    assert render(1e20 * x, "implicit") == "1e+20\u2009*\u2009x"
+    scientific = FormatOptions(mul_style="implicit", scientific=True)
+    assert (2 * x).to_string(scientific) == "2e+0\u2009*\u2009x"
+
+
+def test_walk_paths() -> None:
+    x = symbol("x")
+    y = symbol("y")
+    expr = Expression.mul(x + 1, y)
+
+    paths = dict(expr.walk_paths())
+    assert list(paths) == [(), (0,), (0, 0), (0, 1), (1,)]
+    assert paths[(0,)] == x + 1
+
+    for path, node in expr.walk_paths():
+        assert expr.get_at(path) is node
+
+    assert expr.get_at(()) is expr
+    assert expr.get_at((0, 1)) == x
+    assert expr.get_at((1,)) == y
+    assert expr.get_at((2,)) is None
+    assert expr.get_at((1, 0)) is None
+
+
+def test_canonical_sum_order() -> None:
+    X = unitary_hermitian_operator("X")
+    x = symbol("x")
+    y = symbol("y")
+    z = symbol("z")
+
+    terms = [1j * x, 2 * x * y, (1 + 1j) * z, value(3), value(1j), X(0), -X(1)]
+    forward = reduce(lambda acc, term: acc + term, terms)
+    backward = reduce(lambda acc, term: acc + term, reversed(terms))
+
+    assert repr(forward) == repr(backward)
+    assert str(forward) == str(backward)
+    assert forward[0] == value(3 + 1j)
+
+    assert str(y + x) == str(x + y) == "x + y"
+    assert str((1 + 1j) * y + 2 * x) == str(2 * x + (1 + 1j) * y)
+
+
+def test_canonical_sum_numeric_function_arguments() -> None:
+    X = unitary_hermitian_operator("X")
+    RX = parametric_operator("RX", 3.14)
+    x = symbol("x")
+
+    assert RX(1) + X(0) == X(0) + RX(1)
+    assert function("f", 1.5) + x == x + function("f", 1.5)
+
+
+def test_cached_hash() -> None:
+    X = unitary_hermitian_operator("X")
+    x = symbol("x")
+    y = symbol("y")
+
+    expr = Expression.mul(x + 1, y) * X(0) + function("f", x, y) ** 2
+    for _, node in expr.walk_paths():
+        assert hash(node) == node._compute_hash()
+        assert hash(node) == hash(node)
+
+    # Each level shares the previous one twice, so the tree has `2^depth` paths. Hashing and
+    # deduplicating the levels only visit each distinct node once.
+    levels = [x]
+    for _ in range(200):
+        levels.append(Expression.add(levels[-1], Expression.mul(levels[-1], y)))
+
+    assert len(set(levels + levels)) == len(levels)
+    assert levels[-1] == levels[-1]
+    assert levels[-1] != levels[-2]
+
+    # The cached hash is not pickled, since the hashes of strings change between processes.
+    assert pickle.loads(pickle.dumps(x + y)) == x + y
+    assert pickle.loads(pickle.dumps(x + y))._hash is None
+
+
+def test_pretty() -> None:
+    X = unitary_hermitian_operator("X")
+    Z = unitary_hermitian_operator("Z")
+    a, b, c, d, e = (symbol(name) for name in "abcde")
+
+    # Short expressions stay in a single line.
+    assert (a + 2 * b).pretty(width=80) == str(a + 2 * b)
+
+    hamiltonian = X(0) * Z(1) + 2 * X(1) * Z(2) - 3 * X(2) * Z(0)
+    assert hamiltonian.pretty(width=20) == "\n".join(
+        [
+            "  X[0]\u2009*\u2009Z[1]",
+            "+ 2.0\u2009*\u2009X[1]\u2009*\u2009Z[2]",
+            "- 3.0\u2009*\u2009Z[0]\u2009*\u2009X[2]",
+        ]
+    )
+
+    expr = Expression.mul(a + b, c + d + e)
+    assert expr.pretty(width=10) == "\n".join(
+        ["(a + b)\u2009*\u2009(", "    c", "  + d", "  + e", ")"]
+    )
+
+
+def test_integer_division() -> None:
+    X = unitary_hermitian_operator("X")
+    i = symbol("i")
+    n = symbol("n")
+
+    assert value(7) % 3 == value(1)
+    assert value(7) // 2 == value(3)
+    assert 7 % value(3) == value(1)
+    assert 7 // value(2) == value(3)
+    assert value(-7) % 3 == value(2)
+
+    assert i % n == Expression.function("mod", i, n)
+    assert i // 2 == Expression.function("floordiv", i, value(2))
+    assert str(i % n) == "i % n"
+    assert str((i + 1) % n) == "(1.0 + i) % n"
+    assert str(2 * (i // n)) == "2.0\u2009*\u2009(i // n)"
+
+    with pytest.raises(TypeError):
+        X(0) % 2
+
+    with pytest.raises(TypeError):
+        value(1j) // 2
+
+
+def test_complexity_score() -> None:
+    X = unitary_hermitian_operator("X")
+    x = symbol("x")
+    y = symbol("y")
+
+    assert x.depth == 1
+    assert (2 * x + 1).depth == 3
+
+    # The depth of deep trees doesn't exhaust the stack.
+    deep = reduce(lambda acc, _: Expression.function("f", acc), range(10_000), x)
+    assert deep.depth == 10_001
+    assert Expression.add(deep, value(1)).depth == 10_002
+    assert x.complexity_score() == 3.0
+    assert (x**2).complexity_score() == 10.0
+
+    # Nesting a power in the tree increases the score.
+    cube = Expression.pow(y, value(3))
+    pairs = [
+        (x + y, x + cube),
+        (2 * x * y, 2 * x * cube),
+        (y * X(0), cube * X(0)),
+        (Expression.function("f", x, y) + 1, Expression.function("f", x, cube) + 1),
+        (x + y, Expression.pow(x + y, value(2))),
+    ]
+    for expr, nested in pairs:
+        assert nested.complexity_score() > expr.complexity_score()
+
+    # Powers and function calls weigh more than sums.
+    assert (x**y).complexity_score() > (x + y).complexity_score()
+    assert Expression.function("f", x).complexity_score() > (x + 1).complexity_score()
+
+
+def test_coefficient_accessors() -> None:
+    X = unitary_hermitian_operator("X")
+    x = symbol("x")
+    y = symbol("y")
+
+    term = 3 * x * y
+    assert term.coefficient() == 3
+    assert term.without_coefficient() == x * y
+    assert term.with_coefficient(-2) == -2 * x * y
+    assert term.with_coefficient(1) == x * y
+
+    assert (2 * x).without_coefficient() is x
+    assert (-X(0) * X(1)).coefficient() == -1
+    assert (-X(0) * X(1)).without_coefficient() == X(0) * X(1)
+
+    assert x.coefficient() == 1
+    assert x.without_coefficient() is x
+    assert value(4).coefficient() == 4
+    assert value(4).without_coefficient().is_one
+    assert x.with_coefficient(0).is_zero
+
+    # Sums are not distributed, so the new coefficient can be read back.
+    assert (x + y).with_coefficient(2).coefficient() == 2
+    assert (x + y).with_coefficient(2).without_coefficient() == x + y
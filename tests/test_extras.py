@@ -0,0 +1,30 @@
+from __future__ import annotations
+
+import subprocess
+import sys
+from textwrap import dedent
+
+
+def test_core_without_ir_package() -> None:
+    # Block the import of `qadence2_ir` in a fresh interpreter, as if the `ir` extra was missing.
+    script = dedent(
+        """
+        import sys
+        sys.modules["qadence2_ir"] = None
+
+        from qadence2_expressions import X, compile_to_model, evaluate, parameter, parse
+
+        x = parameter("x")
+        assert evaluate(parse("2 * x + 1"), {"x": 1.0}) == 3.0
+
+        try:
+            compile_to_model(x * X(0))
+        except ImportError as error:
+            assert "pip install qadence2-expressions[ir]" in str(error)
+        else:
+            raise AssertionError("compile_to_model should require qadence2_ir")
+        """
+    )
+
+    result = subprocess.run([sys.executable, "-c", script], capture_output=True, text=True)
+    assert result.returncode == 0, result.stderr
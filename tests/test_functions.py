@@ -1,15 +1,32 @@
 from __future__ import annotations
 
+import math
+
+import pytest
+
 from qadence2_expressions import (
     Expression,
     Support,
+    atan,
+    cbrt,
+    conj,
     cos,
+    cosh,
+    evaluate,
     exp,
+    exp2,
+    function,
     log,
+    log10,
     parameter,
     sin,
+    sinh,
+    split_real_imag,
     sqrt,
+    symbol,
+    tanh,
     unitary_hermitian_operator,
+    value,
     variable,
 )
 
@@ -107,3 +124,75 @@ def test_sqrt() -> None:
         ),
         Support(4),
     )
+
+
+def test_abs() -> None:
+    assert abs(phi) == Expression.function("abs", phi)
+    assert abs(abs(phi)) == abs(phi)
+    assert str(abs(phi + 1)) == "|1.0 + phi|"
+
+    assert abs(value(-2)) == value(2)
+    assert abs(value(3 + 4j)) == value(5)
+    assert evaluate(abs(phi - psi), {"phi": 1, "psi": 3}) == 2
+
+    with pytest.raises(TypeError):
+        abs(X(0))
+
+
+def test_conj() -> None:
+    assert conj(phi) == Expression.function("conj", phi)
+    assert conj(conj(phi)) == phi
+    assert conj(phi + 2j * psi) == conj(phi) - 2j * conj(psi)
+
+    assert conj(value(1 + 2j)) == value(1 - 2j)
+    assert conj(3) == value(3)
+    assert evaluate(conj(phi) * psi, {"phi": 1j, "psi": 2}) == -2j
+
+    with pytest.raises(TypeError):
+        conj(X(0))
+
+
+def test_builtin_functions() -> None:
+    assert sinh(phi) == Expression.function("sinh", phi)
+    assert evaluate(sinh(phi), {"phi": 1}) == pytest.approx(math.sinh(1))
+    assert evaluate(cosh(phi), {"phi": 1}) == pytest.approx(math.cosh(1))
+    assert evaluate(tanh(phi), {"phi": 1}) == pytest.approx(math.tanh(1))
+    assert evaluate(atan(phi), {"phi": 1}) == pytest.approx(math.pi / 4)
+    assert evaluate(exp2(phi), {"phi": 3}) == 8
+    assert evaluate(log10(phi), {"phi": 1000}) == pytest.approx(3)
+    assert evaluate(log10(phi), {"phi": -10}) == pytest.approx(1 + math.pi / math.log(10) * 1j)
+    assert evaluate(cbrt(phi), {"phi": -8}) == pytest.approx(-2)
+    assert evaluate(cbrt(phi), {"phi": 8j}) == pytest.approx(math.sqrt(3) + 1j)
+
+    # Square roots of negative real numbers are exactly imaginary.
+    assert evaluate(sqrt(phi), {"phi": -4}) == 2j
+    assert sqrt(value(-4)) == value(2j)
+
+
+def test_split_real_imag() -> None:
+    x, y, z = symbol("x"), symbol("y"), symbol("z")
+
+    re, im = split_real_imag((x + 1j * y) ** 2)
+    assert re == x**2 - y**2
+    assert im == 2 * x * y
+
+    assert split_real_imag(x * sin(y)) == (x * sin(y), value(0))
+    assert split_real_imag(tanh(x) + cbrt(y)) == (tanh(x) + cbrt(y), value(0))
+    assert split_real_imag(conj(3j * x)) == (value(0), -3 * x)
+    assert split_real_imag(exp(1j * x)) == (cos(x), sin(x))
+
+    # Large powers are not expanded.
+    large = (x + 1j * y) ** 10**9
+    assert split_real_imag(large) == (function("re", large), function("im", large))
+
+    # Complex symbols are split explicitly, and the inseparable parts are wrapped.
+    expr = conj(z) * x + sqrt(z)
+    re, im = split_real_imag(expr, complex_symbols=["z"])
+    assert re == function("re", z) * x + function("re", sqrt(z))
+    assert im == -function("im", z) * x + function("im", sqrt(z))
+
+    values = {"x": 0.5, "z": 1 - 2j}
+    assert evaluate(re, values) + 1j * evaluate(im, values) == pytest.approx(evaluate(expr, values))
+
+    with pytest.raises(TypeError):
+        split_real_imag(X(0))
@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+from qadence2_expressions import (
+    RX,
+    X,
+    Y,
+    Z,
+    Expression,
+    Support,
+    atan,
+    cbrt,
+    cos,
+    cosh,
+    function,
+    is_hermitian,
+    log,
+    parameter,
+    sinh,
+    tanh,
+)
+
+
+def test_hermitian_operators() -> None:
+    theta = parameter("theta")
+
+    assert is_hermitian(X(0) + Z(0))
+    assert is_hermitian(2 * X(0) * Y(1) + cos(theta) * Z(2))
+    assert not is_hermitian(1j * X(0))
+    assert not is_hermitian(X(0) * Y(0))
+    assert not is_hermitian(RX(theta)(0))
+
+    # The real built-in functions of real arguments are real.
+    for fn in [sinh, cosh, tanh, atan, cbrt]:
+        assert is_hermitian(fn(theta) * X(0))
+
+
+def test_user_defined_hermitian_operators() -> None:
+    A = Expression.quantum_operator(Expression.symbol("A"), Support(0))
+
+    assert not is_hermitian(A)
+    assert is_hermitian(A, hermitian_operators={"A"})
+    assert is_hermitian(A + A.dag)
+    assert is_hermitian(1j * A - 1j * A.dag)
+
+
+def test_undecidable_hermiticity() -> None:
+    x = parameter("x")
+
+    assert is_hermitian(function("f", x) * X(0)) is None
+    assert is_hermitian(cos(1j * x) * X(0)) is None
+
+    # The logarithm of a negative number is complex.
+    assert is_hermitian(log(x) * X(0)) is None
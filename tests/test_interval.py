@@ -0,0 +1,59 @@
+from __future__ import annotations
+
+import math
+
+import pytest
+
+from qadence2_expressions import (
+    X,
+    Expression,
+    cos,
+    evaluate_interval,
+    exp,
+    log,
+    parameter,
+    sin,
+    sqrt,
+    value,
+)
+
+x = parameter("x")
+y = parameter("y")
+
+
+def test_arithmetic() -> None:
+    assert evaluate_interval(x**2, {"x": (-1, 2)}) == (0, 4)
+    assert evaluate_interval(x**3, {"x": (-1, 2)}) == (-1, 8)
+    assert evaluate_interval(x**2, {"x": (-3, -1)}) == (1, 9)
+    assert evaluate_interval(Expression.pow(x, value(0)), {"x": (-1, 2)}) == (1, 1)
+    assert evaluate_interval(2 * x - y, {"x": (0, 1), "y": (-1, 3)}) == (-3, 3)
+    assert evaluate_interval(x * y, {"x": (-2, 1), "y": (-1, 3)}) == (-6, 3)
+    assert evaluate_interval(1 / x, {"x": (2, 4)}) == (0.25, 0.5)
+    assert evaluate_interval(sqrt(x), {"x": (4, 9)}) == (2, 3)
+
+    # The bounds are conservative when a symbol is repeated.
+    assert evaluate_interval(x * (1 - x), {"x": (0, 1)}) == (-1, 1)
+
+
+def test_functions() -> None:
+    assert evaluate_interval(exp(x), {"x": (0, 1)}) == (1, math.e)
+    assert evaluate_interval(log(x), {"x": (1, math.e)}) == pytest.approx((0, 1))
+    assert evaluate_interval(abs(x), {"x": (-3, 2)}) == (0, 3)
+    assert evaluate_interval(sin(x), {"x": (0, math.pi)}) == pytest.approx((0, 1))
+    assert evaluate_interval(sin(x), {"x": (0.1, 0.2)}) == (math.sin(0.1), math.sin(0.2))
+    assert evaluate_interval(cos(x), {"x": (-1, 1)}) == pytest.approx((math.cos(1), 1))
+    assert evaluate_interval(cos(x), {"x": (0, 10)}) == (-1, 1)
+
+
+def test_interval_errors() -> None:
+    with pytest.raises(ValueError):
+        evaluate_interval(1 / x, {"x": (-1, 1)})
+
+    with pytest.raises(ValueError):
+        evaluate_interval(log(x), {"x": (-1, 1)})
+
+    with pytest.raises(ValueError):
+        evaluate_interval(x + y, {"x": (0, 1)})
+
+    with pytest.raises(ValueError):
+        evaluate_interval(x * X(0), {"x": (0, 1)})
@@ -1,15 +1,6 @@
 from __future__ import annotations
 
-from qadence2_ir.types import (
-    Alloc,
-    AllocQubits,
-    Assign,
-    Call,
-    Load,
-    Model,
-    QuInstruct,
-    Support,
-)
+import pytest
 
 from qadence2_expressions import (
     RX,
@@ -20,6 +11,9 @@ from qadence2_expressions import (
     reset_ir_options,
 )
 
+# The compilation requires the `ir` extra.
+ir = pytest.importorskip("qadence2_ir.types")
+
 
 def test_ir_compilation() -> None:
     reset_ir_options()
@@ -27,14 +21,14 @@ def test_ir_compilation() -> None:
     expr = Z() * RX(cos(theta / 2))(0)
     model = compile_to_model(expr)
 
-    goal = Model(
-        register=AllocQubits(num_qubits=1),
-        inputs={"theta": Alloc(1, trainable=False)},
+    goal = ir.Model(
+        register=ir.AllocQubits(num_qubits=1),
+        inputs={"theta": ir.Alloc(1, trainable=False)},
         instructions=[
-            QuInstruct("z", Support.target_all()),
-            Assign("%0", Call("mul", 0.5, Load("theta"))),
-            Assign("%1", Call("cos", Load("%0"))),
-            QuInstruct("rx", Support(target=(0,)), Load("%1")),
+            ir.QuInstruct("z", ir.Support.target_all()),
+            ir.Assign("%0", ir.Call("mul", 0.5, ir.Load("theta"))),
+            ir.Assign("%1", ir.Call("cos", ir.Load("%0"))),
+            ir.QuInstruct("rx", ir.Support(target=(0,)), ir.Load("%1")),
         ],
     )
 
@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    CZ,
+    X,
+    Y,
+    Z,
+    Z1,
+    Expression,
+    parameter,
+    to_matrix,
+)
+
+# The dense matrices require the `numpy` extra.
+np = pytest.importorskip("numpy")
+
+
+def test_single_qubit_matrix() -> None:
+    assert np.allclose(to_matrix(X(0), 1), [[0, 1], [1, 0]])
+    assert np.allclose(to_matrix(2 * Z(0) + 1, 1), [[3, 0], [0, -1]])
+    assert np.allclose(to_matrix(Y(0) * Y(0), 1), [[1, 0], [0, 1]])
+    assert np.allclose(to_matrix(Expression.pow(X(0), Expression.value(3)), 1), [[0, 1], [1, 0]])
+
+
+def test_multi_qubit_matrix() -> None:
+    xx = [[0, 0, 0, 1], [0, 0, 1, 0], [0, 1, 0, 0], [1, 0, 0, 0]]
+    assert np.allclose(to_matrix(X(0) * X(1), 2), xx)
+    assert np.allclose(to_matrix(X(0, 1), 2), xx)
+
+    # The qubit 0 is the most significant bit of the basis index.
+    assert np.allclose(to_matrix(Z1(0), 2), np.diag([0, 0, 1, 1]))
+
+    cnot = [[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 0, 1], [0, 0, 1, 0]]
+    assert np.allclose(to_matrix(X(target=(1,), control=(0,)), 2), cnot)
+
+
+def test_matrix_errors() -> None:
+    with pytest.raises(ValueError):
+        to_matrix(parameter("x") * X(0), 1)
+
+    with pytest.raises(ValueError):
+        to_matrix(CZ(0, 1), 2)
+
+    with pytest.raises(ValueError):
+        to_matrix(X(1), 1)
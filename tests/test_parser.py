@@ -0,0 +1,153 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    DepthExceededError,
+    Expression,
+    FormatOptions,
+    ParseError,
+    evaluate,
+    function,
+    parameter,
+    parse,
+    sin,
+    sqrt,
+    to_sympy_string,
+    value,
+)
+
+x = parameter("x")
+y = parameter("y")
+
+
+def test_parse_arithmetic() -> None:
+    assert parse("x + 2*y") == x + 2 * y
+    assert parse("x - y/2") == x - y / 2
+    assert parse("-(x + 1)") == -(x + 1)
+    assert parse("2.5") == value(2.5)
+
+
+def test_parse_integer_division() -> None:
+    assert evaluate(parse("7 % 3")) == 1
+    assert evaluate(parse("7 // 2")) == 3
+    assert evaluate(parse("-7 % 3")) == 2
+
+    # Same precedence as the multiplication, grouping from the left.
+    assert parse("2*x % 3") == (2 * x) % 3
+    assert parse("x // 2 * 3") == (x // 2) * 3
+    assert parse("x % 2 + 1") == x % 2 + 1
+    assert parse("Mod(x, 3)") == x % 3
+    assert evaluate(parse("(x + 1) % 4"), {"x": 7}) == 0
+
+
+def test_parse_power() -> None:
+    assert parse("x**2") == x**2
+    assert parse("x^2") == x**2
+    assert parse("x**-1") == 1 / x
+    assert parse("2**x**2") == 2 ** (x**2)
+
+
+def test_parse_sympy_syntax() -> None:
+    assert parse("x**2 + 3*I") == x**2 + 3j
+    assert parse("sqrt(x)") == sqrt(x)
+    assert parse("sin(x*y)") == sin(x * y)
+    assert parse("exp(x)") == Expression.symbol("E") ** x
+    assert parse("f(x, y)") == function("f", x, y)
+    assert parse("f()") == function("f")
+
+
+def test_parse_errors() -> None:
+    with pytest.raises(SyntaxError):
+        parse("x y")
+
+    with pytest.raises(SyntaxError):
+        parse("(x + 1")
+
+    with pytest.raises(SyntaxError):
+        parse("x + $")
+
+    with pytest.raises(SyntaxError, match="positional argument"):
+        parse("f(a=1, x)")
+
+
+def test_parse_error_spans() -> None:
+    with pytest.raises(ParseError) as error:
+        parse("2 * (x + 1")
+    assert (error.value.start, error.value.end) == (4, 5)
+    assert str(error.value) == (
+        "Invalid expression at position 4: unclosed parenthesis.\n"
+        "    2 * (x + 1\n"
+        "        ^"
+    )
+
+    with pytest.raises(ParseError) as error:
+        parse("sin(x) cos(y)")
+    assert (error.value.start, error.value.end) == (7, 10)
+    assert str(error.value).endswith("\n           ^^^")
+
+    with pytest.raises(ParseError) as error:
+        parse("x + $")
+    assert (error.value.start, error.value.end) == (4, 5)
+
+    with pytest.raises(ParseError) as error:
+        parse("x +")
+    assert error.value.message == "unexpected end of input"
+    assert (error.value.start, error.value.end) == (3, 3)
+
+
+def test_sympy_round_trip() -> None:
+    expr = parse("x**2 + 3*I")
+    assert to_sympy_string(expr) == "3*I + x**2"
+
+    for source in [
+        "x**2 + 3*I",
+        "2*sin(x) - y",
+        "sqrt(x + 1)",
+        "(1 + 2*I)*x**(-1)",
+        "Mod(x, 3) + y",
+        "2*f() + x",
+    ]:
+        expr = parse(source)
+        assert parse(to_sympy_string(expr)) == expr
+
+
+def test_parse_scientific_notation() -> None:
+    assert parse("1.5e-9") == value(1.5e-9)
+    assert parse("2.3E10 * x") == 2.3e10 * x
+    assert parse("2e3") == value(2000)
+
+
+def test_scientific_notation_round_trip() -> None:
+    options = FormatOptions(scientific=True)
+
+    for number in [1.5e-9, 2.3e10]:
+        text = value(number).to_string(options)
+        assert parse(text) == value(number)
+
+    assert value(1.5e-9).to_string(options) == "1.5e-9"
+    assert value(2.3e10).to_string(options) == "2.3e+10"
+    assert value(2.3e10).to_string(FormatOptions(scientific=True, exponent_style="E")) == "2.3E+10"
+    assert value(2.3e10).to_string(FormatOptions(scientific=True, precision=2)) == "2.30e+10"
+    assert value(1.5e-9 - 2e10j).to_string(options) == "(1.5e-9-2e+10j)"
+
+
+def test_parse_max_depth() -> None:
+    nested = "(" * 10_000 + "x" + ")" * 10_000
+
+    with pytest.raises(DepthExceededError):
+        parse(nested)
+
+    # The error is also a parsing error pointing at the offending token.
+    with pytest.raises(ParseError, match="maximum depth of 100") as error:
+        parse(nested)
+    assert error.value.start == 100
+
+    for source in ["-" * 10_000 + "x", "x^" * 10_000 + "x", "f(" * 10_000 + "x" + ")" * 10_000]:
+        with pytest.raises(DepthExceededError):
+            parse(source)
+
+    assert parse("(" * 50 + "x" + ")" * 50) == x
+    assert parse("(" * 150 + "x" + ")" * 150, max_depth=200) == x
+    with pytest.raises(DepthExceededError):
+        parse("((x))", max_depth=2)
@@ -0,0 +1,199 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    X,
+    Expression,
+    as_affine,
+    coefficients,
+    degree,
+    evaluate,
+    factor_common,
+    function,
+    is_identically_one,
+    is_identically_zero,
+    monomials,
+    order_terms_by_degree,
+    parameter,
+    sin,
+    to_horner,
+    to_quadratic_coefficients,
+    to_sop,
+    total_degree,
+    value,
+)
+
+x = parameter("x")
+y = parameter("y")
+
+
+def test_degree() -> None:
+    assert degree(value(3), "x") == 0
+    assert degree(y, "x") == 0
+    assert degree(x**2 * y + x, "x") == 2
+    assert degree((x + 1) ** 3, "x") == 3
+    assert degree(sin(y) * x, "x") == 1
+    assert degree(x * X(0), "x") == 1
+    assert degree(sin(x), "x") is None
+    assert degree(1 / x, "x") is None
+    assert degree(x**y, "x") is None
+
+
+def test_total_degree() -> None:
+    assert total_degree(value(3)) == 0
+    assert total_degree(x**2 * y + x) == 3
+    assert total_degree((x + y) ** 2 * y) == 3
+    assert total_degree(x * y * X(0) + sin(value(1))) == 2
+    assert total_degree(x * sin(y)) is None
+    assert total_degree(x ** (-1)) is None
+    assert total_degree(x**y) is None
+
+
+def test_order_terms_by_degree() -> None:
+    expr = order_terms_by_degree(1 + x**2 + x, "x")
+
+    assert expr == 1 + x**2 + x
+    assert expr.args == (x**2, x, value(1))
+    assert str(expr) == "x\u2009^\u20092.0 + x + 1.0"
+
+
+def test_to_horner() -> None:
+    a = parameter("a")
+    expr = 2 * x**3 + a * x**2 + 4 * x + 5
+    horner = to_horner(expr, "x")
+
+    inner = Expression.add(Expression.mul(value(2), x), a)
+    middle = Expression.add(Expression.mul(inner, x), value(4))
+    assert horner == Expression.add(Expression.mul(middle, x), value(5))
+
+    for point in [-1.5, 0, 2, 3.25]:
+        values = {"x": point, "a": 0.5}
+        assert evaluate(horner, values) == pytest.approx(evaluate(expr, values))
+
+
+def test_to_horner_missing_terms() -> None:
+    assert to_horner(x**2 + 1, "x") == Expression.add(Expression.mul(x, x), value(1))
+    assert to_horner(y + 1, "x") == y + 1
+
+    with pytest.raises(ValueError):
+        to_horner(sin(x) + x, "x")
+
+
+def test_coefficients() -> None:
+    assert coefficients(3 * x**2 + 5, "x") == [5, 0, 3]
+    assert coefficients(x**3 - 2 * x, "x") == [0, -2, 0, 1]
+    assert coefficients(value(4), "x") == [4]
+    assert coefficients(1j * x + sin(value(0)), "x") == [0, 1j]
+
+
+def test_coefficients_errors() -> None:
+    with pytest.raises(ValueError, match="not constant"):
+        coefficients(y * x**2 + 1, "x")
+
+    with pytest.raises(ValueError):
+        coefficients(sin(x) + x, "x")
+
+
+def test_as_affine() -> None:
+    assert as_affine(3 * x + 2 * y, "x") == (value(3), 2 * y)
+    assert as_affine(x * y + sin(y) + 1, "x") == (y, sin(y) + 1)
+    assert as_affine((x + 1) * (y - 2), "x") == (y - 2, y - 2)
+    assert as_affine(2 * y, "x") == (value(0), 2 * y)
+
+    # Nonlinear dependence.
+    assert as_affine(x**2 + x, "x") is None
+    assert as_affine(x * sin(x), "x") is None
+    assert as_affine(x**-1, "x") is None
+
+
+def has_nested_sums(expr: Expression) -> bool:
+    if expr.is_addition:
+        return True
+    if expr.is_function or (expr.is_power and not expr[1][0] > 0):
+        return False
+    return any(has_nested_sums(arg) for arg in expr.args if isinstance(arg, Expression))
+
+
+def test_to_sop() -> None:
+    a, b, c, d = (parameter(name) for name in "abcd")
+
+    expr = to_sop(Expression.mul(a + b, c + d))
+    assert expr == a * c + a * d + b * c + b * d
+    assert expr.is_addition
+    assert all(term.is_multiplication for term in expr.args)
+
+    exprs = [
+        Expression.mul(a + b, Expression.pow(c + d, value(2))),
+        Expression.pow(Expression.add(a, Expression.mul(b, c + d)), value(3)),
+        Expression.add(a, Expression.mul(b, Expression.add(c, Expression.mul(d, a + b)))),
+        (a + X(0)) ** 2 * sin(a + b) + 1 / (c + d),
+    ]
+    values = {"a": 0.3, "b": -1.1, "c": 2.0, "d": 0.7}
+
+    for expr in exprs:
+        result = to_sop(expr)
+        assert result.is_addition
+        assert not any(has_nested_sums(term) for term in result.args)
+        if result.subspace is None:
+            assert evaluate(result, values) == pytest.approx(evaluate(expr, values))
+
+
+def test_factor_common() -> None:
+    a, b, x, y = (parameter(name) for name in "abxy")
+
+    assert factor_common(x * a + x * b) == Expression.mul(x, a + b)
+    assert factor_common(a + b) == a + b
+    assert factor_common(x) == x
+    assert factor_common(x + x * b) == Expression.mul(x, 1 + b)
+    assert factor_common(3 * x * y * a - x * y) == Expression.mul(x, y, 3 * a - 1)
+    assert factor_common(a * X(0) + b * X(0)) == Expression.mul(X(0), a + b)
+
+    expr = 2 * x * y + x**2 * y
+    assert to_sop(factor_common(expr)) == expr
+
+
+def test_is_identically_zero() -> None:
+    x, y = parameter("x"), parameter("y")
+
+    assert is_identically_zero(x - x)
+    assert is_identically_zero(2 * x - x - x)
+    assert is_identically_zero(Expression.add(x, Expression.mul(value(-1), x)))
+    assert is_identically_zero(Expression.mul(x + y, x - y) - x**2 + y**2)
+    assert is_identically_zero(function("f", Expression.add(y, -y)) - function("f", value(0)))
+
+    assert not is_identically_zero(x)
+    assert not is_identically_zero(x - y)
+
+    assert is_identically_one(Expression.mul(x, Expression.pow(x, value(-1))))
+    assert is_identically_one((x + 1) ** 2 - x**2 - 2 * x)
+    assert not is_identically_one(x)
+
+
+def test_to_quadratic_coefficients() -> None:
+    a, b, c, y = (parameter(name) for name in "abcy")
+
+    quadratic, linear, constant = to_quadratic_coefficients(2 * a * b + 3 * a + 1, ["a", "b"])
+    assert quadratic == {(0, 1): 2}
+    assert linear == {0: 3}
+    assert constant == 1
+
+    quadratic, linear, constant = to_quadratic_coefficients((a - b) ** 2 + c - 2, ["a", "b", "c"])
+    assert quadratic == {(0, 0): 1, (0, 1): -2, (1, 1): 1}
+    assert linear == {2: 1}
+    assert constant == -2
+
+    for expr in [a * b * c, y * a, sin(a), a**3]:
+        with pytest.raises(ValueError, match="not quadratic"):
+            to_quadratic_coefficients(expr, ["a", "b", "c"])
+
+
+def test_monomials() -> None:
+    assert monomials(2 * x**2 * y + 3 * x, ["x", "y"]) == {(2, 1): 2, (1, 0): 3}
+    assert monomials((x + y) ** 2 - 2 * x * y + 1, ["x", "y"]) == {(2, 0): 1, (0, 2): 1, (0, 0): 1}
+    assert monomials(x - x, ["x"]) == {}
+    assert monomials(value(5), []) == {(): 5}
+
+    for expr in [x * y, sin(x), x**-1, x**0.5]:
+        with pytest.raises(ValueError, match="not a monomial"):
+            monomials(expr, ["x"])
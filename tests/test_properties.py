@@ -0,0 +1,53 @@
+from __future__ import annotations
+
+import pytest
+from hypothesis import given
+from strategies import expressions, raw_expressions
+
+from qadence2_expressions import (
+    Expression,
+    evaluate,
+    fold_powers,
+    fold_signs,
+    from_json,
+    prune_zeros,
+    replace,
+    to_json,
+)
+
+VALUES = {"x": 0.3, "y": -1.2, "z": 2.5}
+
+
+def close_or_undefined(expr: Expression, other: Expression) -> bool:
+    """Compare the numerical values of two expressions that may be undefined, e.g., `0^-1`."""
+
+    try:
+        expected = evaluate(expr, VALUES)
+    except (ZeroDivisionError, OverflowError):
+        return True
+
+    return evaluate(other, VALUES) == pytest.approx(expected, rel=1e-9, abs=1e-9)
+
+
+@given(raw_expressions)
+def test_simplifications_are_idempotent(expr: Expression) -> None:
+    for simplify in (prune_zeros, fold_signs, fold_powers):
+        once = simplify(expr)
+        assert simplify(once) == once
+
+
+@given(raw_expressions)
+def test_simplifications_preserve_values(expr: Expression) -> None:
+    assert close_or_undefined(expr, prune_zeros(expr))
+    assert close_or_undefined(expr, fold_signs(expr))
+    assert close_or_undefined(expr, fold_powers(expr))
+
+
+@given(expressions)
+def test_replace_without_rules(expr: Expression) -> None:
+    assert replace(expr, {}) is expr
+
+
+@given(expressions)
+def test_serialization_round_trip(expr: Expression) -> None:
+    assert from_json(to_json(expr)) == expr
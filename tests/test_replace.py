@@ -1,12 +1,27 @@
 from __future__ import annotations
 
 from qadence2_expressions import (
+    RX,
     X,
     Y,
     Z,
+    Expression,
+    cos,
+    function,
+    indexed,
+    noncommutative_multiplication,
     parameter,
+    parametric_operator,
+    prefix_symbols,
+    reindex,
+    rename_symbols,
     replace,
+    replace_operator,
+    replace_symbols,
+    subs,
+    substitute_many_exprs,
     value,
+    variable,
 )
 
 
@@ -43,3 +58,138 @@ def test_replace_expression_by_expression() -> None:
     expr0 = 2j * Y() + X() * Z()
     expr1 = replace(expr0, {X() * Z(): -2j * Y()})
     assert expr1 == value(0)
+
+
+def test_replace_simultaneously() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    expr = replace(x + 2 * y, {x: y, y: x})
+    assert expr == y + 2 * x
+
+
+def test_substitute_many_exprs() -> None:
+    a, b, x, y = parameter("a"), parameter("b"), parameter("x"), parameter("y")
+
+    expr = cos(x + 1) * y
+    assert substitute_many_exprs(expr, [(x + 1, a), (y, b)]) == cos(a) * b
+
+    # The replacements are not rewritten by the other rules.
+    assert substitute_many_exprs(expr, [(x + 1, y), (y, b)]) == cos(y) * b
+    assert substitute_many_exprs(x + 2 * y, [(x, y), (y, x)]) == y + 2 * x
+
+    # The first rule wins, and only whole subexpressions are matched.
+    assert substitute_many_exprs(expr, [(y, a), (y, b)]) == cos(x + 1) * a
+    assert substitute_many_exprs(x + y + 1, [(x + 1, a)]) == x + y + 1
+
+
+def test_subs() -> None:
+    x, y, z = parameter("x"), parameter("y"), parameter("z")
+
+    expr = Expression.add(Expression.mul(x, y), Expression.mul(value(0), z))
+    assert subs(expr, {"x": 2}) == 2 * y
+    assert subs(x * y + z, {"x": 2, "y": 3, "z": -6}) == value(0)
+    assert subs(cos(x) * y, {"y": x}) == cos(x) * x
+    assert subs(RX(x)(0) + x * X(1), {"x": 0.5}) == RX(0.5)(0) + 0.5 * X(1)
+    assert subs(indexed("a", x + 1), {"x": 1}) == indexed("a", 2)
+
+    # Operator names are not replaced.
+    assert subs(X(0), {"X": value(1)}) == X(0)
+
+    # Raw numerical arguments of functions are kept.
+    rx = parametric_operator("RX", 3.14)
+    assert subs(function("f", 1.5, x), {"x": 2}) == function("f", 1.5, value(2))
+    assert subs(rx(1) + x, {"x": 2}) == rx(1) + 2
+
+
+def test_replace_keeps_noncommutative_products() -> None:
+    a, b, c = parameter("a"), parameter("b"), parameter("c")
+
+    with noncommutative_multiplication():
+        expr = b * a
+
+    result = replace(expr, {a: c})
+    assert not result.is_commutative
+    assert result.args == (b, c)
+
+
+def test_replace_reuses_unchanged_subexpressions() -> None:
+    a = parameter("a")
+    b = parameter("b")
+    c = parameter("c")
+
+    branch = cos(2 * a * b + X(0) * Y(1))
+    expr = branch + c**2
+
+    result = replace(expr, {c: value(2)})
+    assert result == branch + 4
+    assert any(term is branch for term in result.args)
+
+    # Nothing to replace, the expression is returned as it is.
+    assert replace(expr, {parameter("d"): a}) is expr
+
+
+def test_replace_symbols_with_function() -> None:
+    x = parameter("x")
+    thetas = [parameter(f"theta_{k}") for k in range(3)]
+    expr = thetas[0] * X(0) + thetas[1] * cos(thetas[2]) * x
+
+    def rescale(name: str) -> Expression | None:
+        if name.startswith("theta_"):
+            k = int(name.removeprefix("theta_"))
+            return (k + 1) * parameter(name)
+        return None
+
+    result = replace_symbols(expr, rescale)
+    assert result == thetas[0] * X(0) + 2 * thetas[1] * cos(3 * thetas[2]) * x
+
+    assert replace_symbols(expr, lambda _: None) is expr
+
+
+def test_replace_operator() -> None:
+    x = parameter("x")
+    product = Expression.mul(X(0), Y(1), Z(2))
+
+    result = replace_operator(product, Expression.Tag.MUL, Expression.Tag.KRON)
+    assert result.is_kronecker_product
+    assert result.args == product.args
+
+    expr = cos(x) + 2 * x * cos(x)
+    result = replace_operator(expr, Expression.Tag.MUL, Expression.Tag.KRON)
+    assert result == Expression.add(cos(x), Expression.kron(value(2), x, cos(x)))
+
+    assert replace_operator(expr, Expression.Tag.POW, Expression.Tag.MUL) is expr
+
+
+def test_rename_symbols() -> None:
+    theta = parameter("theta")
+    w = variable("w")
+    expr = theta * X(0) + RX(w)(1) * cos(theta)
+
+    result = rename_symbols(expr, str.upper)
+    assert result == parameter("THETA") * X(0) + RX(variable("W"))(1) * cos(parameter("THETA"))
+    assert result.free_symbols == {"THETA", "W"}
+
+
+def test_prefix_symbols_avoids_collisions() -> None:
+    theta = parameter("theta")
+    circuit = theta * X(0)
+
+    combined = prefix_symbols(circuit, "a_") + prefix_symbols(circuit, "b_")
+    assert combined == parameter("a_theta") * X(0) + parameter("b_theta") * X(0)
+    assert combined.free_symbols == {"a_theta", "b_theta"}
+
+    assert prefix_symbols(X(0) + value(1), "a_") == X(0) + 1
+
+
+def test_reindex() -> None:
+    i = parameter("i")
+    q = [indexed("q", k) for k in range(3)]
+
+    expr = q[0] * q[1] + q[2]
+    assert reindex(expr, "q", {0: 5, 1: 3}) == indexed("q", 5) * indexed("q", 3) + q[2]
+
+    expr = indexed("p", 0) * q[0] + indexed("q", i)
+    assert reindex(expr, "q", {0: 1}) == indexed("p", 0) * q[1] + indexed("q", i)
+
+    assert reindex(expr, "r", {0: 1}) is expr
@@ -0,0 +1,162 @@
+from __future__ import annotations
+
+import json
+
+import pytest
+
+from qadence2_expressions import (
+    CZ,
+    RX,
+    X,
+    Z,
+    DepthExceededError,
+    Expression,
+    from_dict,
+    from_json,
+    function,
+    parameter,
+    parametric_operator,
+    sin,
+    symbol,
+    to_dict,
+    to_json,
+    value,
+)
+from qadence2_expressions.serialization import SERIALIZATION_VERSION
+
+
+def test_round_trip() -> None:
+    x = symbol("x")
+    theta = parameter("theta")
+
+    exprs = [
+        value(2),
+        value(1 - 2j),
+        2 * x + sin(theta) ** 2,
+        X(0) * Z(1) + 0.5j * CZ(target=(1,), control=(0,)),
+        RX(theta)(2) * RX(x)(2),
+        Expression.indexed("w", 3) * x,
+    ]
+
+    for expr in exprs:
+        assert from_json(to_json(expr)) == expr
+
+
+def test_round_trip_numeric_function_arguments() -> None:
+    expr = parametric_operator("RX", 3.14)(1) + function("f", 1.5)
+
+    assert from_json(to_json(expr)) == expr
+
+
+def test_round_trip_keeps_structure() -> None:
+    x = symbol("x")
+    expr = Expression.add(Expression.mul(x, x), value(0))
+
+    restored = from_dict(to_dict(expr))
+    assert restored.args == expr.args
+
+
+def test_tags_stored_by_name() -> None:
+    data = to_dict(symbol("x") + 1)
+
+    assert data["version"] == SERIALIZATION_VERSION
+    assert data["expr"]["tag"] == "+"
+    assert {arg["tag"] for arg in data["expr"]["args"]} == {"Value", "Symbol"}
+
+
+def test_version_mismatch() -> None:
+    data = to_dict(symbol("x"))
+
+    data["version"] = SERIALIZATION_VERSION + 1
+    with pytest.raises(ValueError, match="format version"):
+        from_dict(data)
+
+    del data["version"]
+    with pytest.raises(ValueError, match="no format version"):
+        from_dict(data)
+
+
+def test_unserializable_attribute() -> None:
+    expr = Expression.symbol("x", join=lambda a, b: a + b)
+
+    with pytest.raises(TypeError):
+        to_dict(expr)
+
+
+def test_callable_attribute() -> None:
+    expr = RX(parameter("theta"))(0)
+    data = to_dict(expr)
+
+    assert from_dict(data) == expr
+    assert from_dict(data).get("join") is expr.get("join")
+
+
+def test_foreign_callable_rejected() -> None:
+    data = to_dict(Expression.symbol("x", join=None))
+
+    for name in ["os:system", "qadence2_expressions.serialization:import_module"]:
+        data["expr"]["attrs"]["join"] = {"callable": name}
+        with pytest.raises(ValueError, match="callable"):
+            from_dict(data)
+
+    with pytest.raises(TypeError):
+        to_dict(Expression.symbol("x", join=print))
+
+
+def test_malformed_data() -> None:
+    data = to_dict(parameter("x") ** 2)
+    del data["expr"]["args"][1]
+
+    with pytest.raises(SyntaxError):
+        from_dict(data)
+
+
+def test_invalid_documents() -> None:
+    with pytest.raises(ValueError, match="dictionary"):
+        from_dict([])  # type: ignore [arg-type]
+
+    with pytest.raises(ValueError, match="no expression"):
+        from_dict({"version": 1})
+
+    with pytest.raises(ValueError, match=r"at \(\)"):
+        from_dict({"version": 1, "expr": {"tag": "+"}})
+
+    # The errors locate the offending node.
+    data = to_dict(parameter("x") + 1j)
+    data["expr"]["args"][1]["args"] = {"x": 1}
+    with pytest.raises(ValueError, match=r"at \(1,\)"):
+        from_dict(data)
+
+    for node in [{"tag": "Nope", "args": [], "attrs": {}}, {"complex": [1]}, {"other": 1}]:
+        data = to_dict(parameter("x") + 1j)
+        data["expr"]["args"][0]["args"] = [node]
+        with pytest.raises(ValueError, match=r"at \(0, 0\)"):
+            from_dict(data)
+
+
+def test_depth_limit() -> None:
+    x = parameter("x")
+
+    deep = x
+    for _ in range(10_000):
+        deep = Expression.add(Expression.mul(value(2), deep), value(1))
+
+    with pytest.raises(DepthExceededError):
+        to_dict(deep, max_depth=100)
+
+    # Untrusted documents are rejected before the expression is rebuilt.
+    data = to_dict(x)
+    for _ in range(10_000):
+        data["expr"] = {"tag": "+", "args": [data["expr"], data["expr"]], "attrs": {}}
+    with pytest.raises(DepthExceededError):
+        from_dict(data, max_depth=100)
+
+    node = json.dumps(to_dict(x)["expr"])
+    source = '{"version": 1, "expr": ' + '{"tag": "+", "attrs": {}, "args": [' * 100_000 + node
+    source += "]}" * 100_000 + "}"
+    with pytest.raises(DepthExceededError):
+        from_json(source, max_depth=100)
+
+    assert from_dict(to_dict(sin(x), max_depth=2), max_depth=2) == sin(x)
+    with pytest.raises(DepthExceededError):
+        from_dict(to_dict(sin(x)), max_depth=1)
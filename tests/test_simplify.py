@@ -0,0 +1,270 @@
+from __future__ import annotations
+
+import logging
+
+import pytest
+
+from qadence2_expressions import (
+    X,
+    Y,
+    Z,
+    Expression,
+    collapse_singletons,
+    combine_like_terms,
+    cos,
+    drop_small_terms,
+    equals_up_to_scalar,
+    evaluate,
+    expand_integer_power,
+    fold_powers,
+    fold_signs,
+    noncommutative_multiplication,
+    prune_zeros,
+    rationalize_denominators,
+    simplify_trig,
+    sin,
+    symbol,
+    value,
+)
+
+
+def test_prune_zero_terms() -> None:
+    zero = value(0)
+    expr = Expression.add(X(0), Expression.mul(zero, X(1)), X(2))
+    assert prune_zeros(expr) == Expression.add(X(0), X(2))
+
+    expr = Expression.add(Expression.mul(zero, X(1)), X(2))
+    assert prune_zeros(expr) == X(2)
+
+    expr = Expression.add(Expression.mul(zero, X(1)), zero)
+    assert prune_zeros(expr) == zero
+
+
+def test_prune_nested_zeros() -> None:
+    x = symbol("x")
+    zero = value(0)
+
+    expr = cos(Expression.add(x, Expression.mul(x, zero)))
+    assert prune_zeros(expr) == cos(x)
+
+    expr = Expression.pow(Expression.add(x, Expression.kron(zero, X(0))), value(2))
+    assert prune_zeros(expr) == Expression.pow(x, value(2))
+
+
+def test_prune_zeros_keeps_unchanged_expressions() -> None:
+    x = symbol("x")
+    expr = 2 * x + cos(x)
+    assert prune_zeros(expr) is expr
+
+
+def test_fold_signs() -> None:
+    x = symbol("x")
+    y = symbol("y")
+    minus = value(-1)
+
+    assert fold_signs(Expression.mul(minus, minus, x)) == x
+    assert fold_signs(Expression.mul(minus, minus, minus, x)) == -x
+    assert fold_signs(Expression.mul(minus, Expression.mul(minus, Expression.mul(minus, x)))) == -x
+    assert fold_signs(Expression.mul(value(-2), Expression.mul(minus, x, y))) == 2 * x * y
+    assert fold_signs(Expression.mul(minus, minus)) == value(1)
+
+    expr = cos(Expression.mul(minus, Expression.mul(minus, x)))
+    assert fold_signs(expr) == cos(x)
+
+
+def test_fold_signs_keeps_canonical_products() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    expr = -2 * x * y + cos(-x)
+    assert fold_signs(expr) is expr
+
+
+def test_fold_powers() -> None:
+    x = symbol("x")
+    pow = Expression.pow
+
+    assert fold_powers(pow(pow(x, value(2)), value(3))) == pow(x, value(6))
+    assert fold_powers(pow(pow(pow(x, value(2)), value(3)), value(0.5))) == pow(x, value(3))
+    assert fold_powers(pow(pow(x, value(2)), value(0.5))) == x
+    assert fold_powers(pow(pow(x, value(2)), value(0))) == value(1)
+    assert fold_powers(cos(pow(pow(x, value(-1)), value(-1)))) == cos(x)
+
+
+def test_fold_powers_stops_at_symbolic_exponents() -> None:
+    x = symbol("x")
+    n = symbol("n")
+    pow = Expression.pow
+
+    expr = pow(pow(pow(x, value(2)), value(3)), n)
+    assert fold_powers(expr) == pow(pow(x, value(6)), n)
+
+    expr = pow(pow(x, n), value(2))
+    assert fold_powers(expr) is expr
+
+
+def test_drop_small_terms() -> None:
+    expr = 0.5 * Z(0) + 0.001 * X(0) - 0.002 * Z(0) * Z(1)
+
+    assert drop_small_terms(expr, 0.01) == 0.5 * Z(0)
+    assert drop_small_terms(expr, 0.0015) == 0.5 * Z(0) - 0.002 * Z(0) * Z(1)
+    assert drop_small_terms(expr, 1) == value(0)
+    assert drop_small_terms(expr, 0.0001) is expr
+
+    result, dropped = drop_small_terms(expr, 0.01, return_dropped=True)
+    assert result == 0.5 * Z(0)
+    assert dropped == pytest.approx(0.003)
+
+
+def test_drop_small_terms_keeps_symbolic_coefficients() -> None:
+    theta = symbol("theta")
+    expr = 0.001 * theta * X(0) + 0.001 * X(1) + value(0.001)
+
+    assert drop_small_terms(expr, 0.01) == 0.001 * theta * X(0)
+
+
+def test_rationalize_numerical_denominators() -> None:
+    expr = Expression.pow(value(1 + 1j), value(-1))
+    assert rationalize_denominators(expr) == (1 - 1j) / value(2)
+
+
+def test_rationalize_symbolic_denominators() -> None:
+    x = symbol("x")
+
+    def has_complex_denominators(expr: Expression) -> bool:
+        if expr.is_power and expr[1].is_value and expr[1][0] < 0 and expr[0].dag != expr[0]:
+            return True
+        return any(
+            has_complex_denominators(arg) for arg in expr.args if isinstance(arg, Expression)
+        )
+
+    for expr in [1 / (x + 1j), x / (2 * x + 3j * cos(x)), (1 - 2j * x) ** -2]:
+        result = rationalize_denominators(expr)
+        assert has_complex_denominators(expr)
+        assert not has_complex_denominators(result)
+        assert evaluate(result, {"x": 0.7}) == pytest.approx(evaluate(expr, {"x": 0.7}))
+
+    expr = 1 / (x + 1)
+    assert rationalize_denominators(expr) is expr
+
+
+def test_equals_up_to_scalar() -> None:
+    x = symbol("x")
+
+    assert equals_up_to_scalar(2 * X(0), 3 * X(0)) == pytest.approx(2 / 3)
+    assert equals_up_to_scalar(X(0), Y(0)) is None
+    assert equals_up_to_scalar(X(0), X(1)) is None
+    assert equals_up_to_scalar(1j * X(0), X(0)) == 1j
+
+    assert equals_up_to_scalar(2 * x * X(0) + 4 * Z(1), x * X(0) + 2 * Z(1)) == 2
+    assert equals_up_to_scalar(x * X(0) + 4 * Z(1), x * X(0) + 2 * Z(1)) is None
+    assert equals_up_to_scalar(x * X(0) + Z(1), x * X(0)) is None
+
+    assert equals_up_to_scalar(value(0), X(0)) == 0
+    assert equals_up_to_scalar(X(0), value(0)) is None
+
+    # The coefficient of noncommutative products is stripped too, keeping the factors' order.
+    a, b = symbol("a"), symbol("b")
+    with noncommutative_multiplication():
+        ab, ba = a * b, b * a
+        assert equals_up_to_scalar(2 * a * b, ab) == 2
+    assert equals_up_to_scalar(ab, ba) is None
+
+
+def test_simplify_trig_pythagorean_identity() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert simplify_trig(sin(x) ** 2 + cos(x) ** 2) == value(1)
+    assert simplify_trig(2 * y * sin(x) ** 2 + 2 * y * cos(x) ** 2 + x) == 2 * y + x
+    assert simplify_trig(sin(x) ** 2 * X(0) + cos(x) ** 2 * X(0)) == X(0)
+
+    # Only matching arguments and factors are combined.
+    for expr in [sin(x) ** 2 + cos(y) ** 2, 2 * sin(x) ** 2 + cos(x) ** 2]:
+        assert simplify_trig(expr) == expr
+
+
+def test_simplify_trig_parity() -> None:
+    x = symbol("x")
+
+    assert simplify_trig(sin(value(0))) == value(0)
+    assert simplify_trig(cos(value(0))) == value(1)
+    assert simplify_trig(sin(-x)) == -sin(x)
+    assert simplify_trig(sin(-2 * x)) == -sin(2 * x)
+    assert simplify_trig(cos(-x)) == cos(x)
+    assert simplify_trig(sin(-x) ** 2 + cos(x) ** 2) == value(1)
+
+    assert simplify_trig(sin(x)) == sin(x)
+    assert simplify_trig(sin(-1j * x)) == sin(-1j * x)
+
+    # Raw numerical arguments are handled like values.
+    assert simplify_trig(Expression.function("sin", 0)) == value(0)
+    assert simplify_trig(Expression.function("cos", 1.5)) == Expression.function("cos", 1.5)
+
+
+def test_combine_like_terms() -> None:
+    x = symbol("x")
+    y = symbol("y")
+
+    assert combine_like_terms(Expression.add(x, x)) == 2 * x
+    assert combine_like_terms(Expression.add(x, Expression.mul(value(2), x))) == 3 * x
+    assert combine_like_terms(Expression.add(x, Expression.mul(value(-1), x), y)) == y
+    assert combine_like_terms(Expression.add(x, Expression.mul(value(-1), x))).is_zero
+
+    # Products are compared regardless of the order of their factors.
+    xy = Expression.add(Expression.mul(x, y), Expression.mul(value(3), y, x), value(1), value(2))
+    assert combine_like_terms(xy) == 4 * x * y + 3
+
+    # Sums without like terms are kept as they are.
+    expr = Expression.add(x, y)
+    assert combine_like_terms(expr) is expr
+
+
+def test_collapse_singletons() -> None:
+    x = symbol("x")
+
+    assert collapse_singletons(Expression.add(x)) is x
+    assert collapse_singletons(Expression.mul()) == value(1)
+    assert collapse_singletons(Expression.add()) == value(0)
+    assert collapse_singletons(Expression.kron(X(0))) == X(0)
+
+    nested = Expression.add(Expression.mul(Expression.add(x)), Expression.mul(), X(1))
+    assert collapse_singletons(nested) == Expression.add(x, value(1), X(1))
+
+    expr = Expression.add(x, value(2))
+    assert collapse_singletons(expr) is expr
+
+
+def test_expand_integer_power() -> None:
+    x = symbol("x")
+    square = Expression.mul(x, x)
+
+    assert expand_integer_power(x**3) == Expression.mul(x, square)
+    assert expand_integer_power(x**4) == Expression.mul(square, square)
+    assert expand_integer_power(sin(x**2) * x**-2) == sin(square) * x**-2
+    assert expand_integer_power(Expression.pow(x, value(0))) == value(1)
+
+    # The squares are shared, keeping the tree shallow.
+    expanded = expand_integer_power(x**8)
+    assert expanded[0] is expanded[1]
+    assert expanded.depth == 4
+    assert evaluate(expanded, {"x": 1.5}) == pytest.approx(1.5**8)
+
+    # Large, negative, non-integer, and symbolic exponents are kept.
+    for expr in [x**100, x**-3, x**0.5, x ** symbol("n")]:
+        assert expand_integer_power(expr) == expr
+    assert expand_integer_power(x**3, max_exponent=2) == x**3
+
+
+def test_rewrite_tracing(caplog: pytest.LogCaptureFixture) -> None:
+    x = symbol("x")
+    expr = Expression.add(x, value(0))
+
+    with caplog.at_level(logging.DEBUG, logger="qadence2_expressions.simplify"):
+        assert prune_zeros(expr) == x
+
+    assert caplog.messages == ["prune_zeros.<locals>.prune, step 1: x + 0.0 -> x"]
+
+    # Nothing is logged above the debug level.
+    prune_zeros(expr)
+    assert len(caplog.messages) == 1
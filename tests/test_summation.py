@@ -0,0 +1,117 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    EvalContext,
+    Expression,
+    evaluate,
+    expand_sum,
+    function,
+    indexed,
+    kronecker_delta,
+    levi_civita,
+    parameter,
+    parameters,
+    reduce_index_functions,
+    replace,
+    summation,
+    value,
+)
+
+i = parameter("i")
+n = parameter("n")
+
+
+def test_indexed_symbols() -> None:
+    assert indexed("x", 0) == Expression(Expression.Tag.SYMBOL, "x", index=0)
+    assert indexed("x", value(1)) == indexed("x", 1)
+    assert indexed("x", 0) != indexed("x", 1)
+    assert indexed("x", 0) + indexed("x", 0) == 2 * indexed("x", 0)
+    assert str(indexed("x", i)) == "x[i]"
+    assert evaluate(indexed("x", i + 1), {"x": [1, 2, 3], "i": 1}) == 3
+
+
+def test_expand_sum() -> None:
+    expr = summation("i", 0, 2, indexed("x", i))
+    assert expand_sum(expr) == indexed("x", 0) + indexed("x", 1) + indexed("x", 2)
+
+    expr = 2 * summation("i", 1, 3, i * indexed("x", i - 1))
+    assert expand_sum(expr) == 2 * indexed("x", 0) + 4 * indexed("x", 1) + 6 * indexed("x", 2)
+
+    # Empty ranges add up to zero.
+    empty = expand_sum(summation("i", 3, 1, indexed("x", i)))
+    assert isinstance(empty, Expression) and empty.is_zero
+
+
+def test_symbolic_bounds() -> None:
+    expr = summation("i", 0, n, indexed("x", i))
+    assert expand_sum(expr) is expr
+    assert str(expr) == "sum(x[i],\u2009i,\u20090.0,\u2009n)"
+
+
+def test_summation_index_is_bound() -> None:
+    expr = summation("i", 0, n, i * indexed("x", i))
+
+    assert expr.free_symbols == {"n", "x"}
+    assert parameters(expr) == ["n", "x"]
+    assert (expr + i).free_symbols == {"i", "n", "x"}
+
+    # Functions named `sum` are not summations.
+    user_sum = function("sum", indexed("x", i), i, value(0), value(2))
+    assert expand_sum(user_sum) is user_sum
+    assert user_sum.free_symbols == {"i", "x"}
+
+
+def test_kronecker_delta() -> None:
+    j = parameter("j")
+
+    assert kronecker_delta(2, 2) == value(1)
+    assert kronecker_delta(1, 2) == value(0)
+    assert kronecker_delta(i, i) == value(1)
+    assert kronecker_delta(i, 2) == Expression(
+        Expression.Tag.FN, Expression.symbol("delta"), i, value(2), index_symbol=True
+    )
+
+    expr = kronecker_delta(i, j) * indexed("x", j)
+    assert reduce_index_functions(replace(expr, {i: value(1), j: value(1)})) == indexed("x", 1)
+    assert reduce_index_functions(replace(expr, {i: value(0), j: value(1)})) == value(0)
+    assert evaluate(expr, {"i": 1, "j": 1, "x": [2, 3]}) == 3
+
+    expr = summation("i", 0, 2, kronecker_delta(i, 1) * indexed("x", i))
+    assert expand_sum(expr) == indexed("x", 1)
+
+
+def test_levi_civita() -> None:
+    j, k = parameter("j"), parameter("k")
+
+    assert levi_civita(1, 2, 3) == value(1)
+    assert levi_civita(2, 3, 1) == value(1)
+    assert levi_civita(2, 1, 3) == value(-1)
+    assert levi_civita(3, 2, 1) == value(-1)
+    assert levi_civita(1, 1, 3) == value(0)
+    assert levi_civita(i, j, i) == value(0)
+    assert levi_civita(i, j, k).get("index_symbol")
+    assert str(levi_civita(i, j, k)) == str(function("epsilon", i, j, k))
+
+    expr = levi_civita(i, j, k)
+    assert reduce_index_functions(replace(expr, {i: value(0), j: value(2), k: value(1)})) == -1
+    assert evaluate(expr, {"i": 2, "j": 0, "k": 1}) == 1
+
+
+def test_user_index_function_names() -> None:
+    j = parameter("j")
+    context = EvalContext()
+    context.register("delta", lambda x: 2 * x)
+
+    # Functions named like the index symbols are neither reduced nor evaluated as them.
+    expr = function("delta", i, j) + function("epsilon", value(1), value(2))
+    assert kronecker_delta(i, j) != function("delta", i, j)
+    assert reduce_index_functions(replace(expr, {i: value(1), j: value(1)})) == replace(
+        expr, {i: value(1), j: value(1)}
+    )
+    assert evaluate(function("delta", i), {"i": 1}, context) == 2
+    assert evaluate(kronecker_delta(i, j), {"i": 1, "j": 1}, context) == 1
+
+    with pytest.raises(ValueError, match="Unknown function"):
+        evaluate(function("delta", i), {"i": 1})
@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import pytest
+
+from qadence2_expressions import (
+    CZ,
+    RX,
+    X,
+    Z,
+    Tensor,
+    parameter,
+    to_tensor_network,
+)
+
+
+def test_operator_chain() -> None:
+    network = to_tensor_network(X(0) * Z(0))
+
+    assert network.tensors == (
+        Tensor(Z(0), (0,), ("q0_1", "q0_0")),
+        Tensor(X(0), (0,), ("q0_2", "q0_1")),
+    )
+    assert network.inputs == {0: "q0_0"}
+    assert network.outputs == {0: "q0_2"}
+    assert network.subscripts() == "ab,ca->cb"
+
+
+def test_multi_qubit_network() -> None:
+    theta = parameter("theta")
+    network = to_tensor_network(2 * X(1) * CZ(target=(1,), control=(0,)) * RX(theta)(0))
+
+    assert network.scale == 2
+    assert [tensor.qubits for tensor in network.tensors] == [(0,), (1, 0), (1,)]
+    assert network.tensors[1].indices == ("q1_1", "q0_2", "q1_0", "q0_1")
+    assert network.inputs == {0: "q0_0", 1: "q1_0"}
+    assert network.outputs == {0: "q0_2", 1: "q1_2"}
+    assert network.subscripts() == "ab,cdea,fc->dfbe"
+
+
+def test_unsupported_expressions() -> None:
+    with pytest.raises(ValueError):
+        to_tensor_network(X(0) + Z(0))
+
+    with pytest.raises(ValueError):
+        to_tensor_network(X())
@@ -0,0 +1,143 @@
+from __future__ import annotations
+
+import math
+import re
+
+import pytest
+
+from qadence2_expressions import (
+    X,
+    DepthExceededError,
+    EvalContext,
+    Expression,
+    check_depth,
+    compile_closure,
+    compile_expression,
+    evaluate,
+    fold_signs,
+    from_json,
+    non_finite_paths,
+    parameter,
+    partial_evaluate,
+    prune_zeros,
+    replace_non_finite,
+    sin,
+    substitute_value,
+    to_horner,
+    to_json,
+    validate,
+    validate_wellformed,
+    value,
+)
+from qadence2_expressions.simplify import rewrite_bottom_up
+
+
+def test_non_finite_paths() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    assert non_finite_paths(x + 2 * y) == []
+    assert non_finite_paths(value(math.inf)) == [()]
+
+    expr = x + value(math.inf) * y
+    [path] = non_finite_paths(expr)
+    assert expr[path[0]][path[1]] == value(math.inf)
+
+    expr = sin(value(math.nan) * x) * X(0)
+    assert len(non_finite_paths(expr)) == 1
+
+
+def test_validate() -> None:
+    x = parameter("x")
+    validate(2 * x + X(0))
+
+    expr = x + value(-math.inf) * X(0)
+    with pytest.raises(ValueError, match="non-finite"):
+        validate(expr)
+
+
+def test_replace_non_finite() -> None:
+    x = parameter("x")
+    y = parameter("y")
+
+    expr = 2 * x + X(0)
+    assert replace_non_finite(expr, 0.0) == (expr, 0)
+
+    result, count = replace_non_finite(x + value(math.inf) * y, 0.0)
+    assert count == 1
+    assert result == Expression.add(x, Expression.mul(value(0.0), y))
+    assert non_finite_paths(result) == []
+
+    result, count = replace_non_finite(sin(value(math.nan) * x) + value(-math.inf), 1.0)
+    assert count == 2
+    validate(result)
+
+
+def test_check_depth() -> None:
+    x = parameter("x")
+
+    check_depth(x, 1)
+    check_depth(sin(x + 1), 3)
+    with pytest.raises(DepthExceededError):
+        check_depth(sin(x + 1), 2)
+
+    deep = x
+    for _ in range(10_000):
+        deep = Expression.add(Expression.mul(value(2), deep), value(1))
+
+    with pytest.raises(DepthExceededError):
+        check_depth(deep, 1000)
+
+    context = EvalContext(max_depth=1000)
+    with pytest.raises(DepthExceededError):
+        evaluate(deep, {"x": 0}, context)
+    with pytest.raises(DepthExceededError):
+        compile_expression(deep, context)
+    with pytest.raises(DepthExceededError):
+        compile_closure(deep, ["x"], context)
+    with pytest.raises(DepthExceededError):
+        partial_evaluate(deep, {"x": 0}, context)
+
+    with pytest.raises(DepthExceededError):
+        rewrite_bottom_up(deep, lambda expr: expr, max_depth=1000)
+
+    assert evaluate(2 * x + 1, {"x": 1}, context) == 3
+    assert compile_expression(2 * x + 1, context)([1]) == 3
+    assert partial_evaluate(2 * x + 1, {"x": 1}, context) == value(3)
+
+
+def test_no_default_depth_limit() -> None:
+    x = parameter("x")
+
+    # The Horner form of a polynomial of degree 129 is 259 levels deep.
+    expr = to_horner(sum((k + 1) * x**k for k in range(130)), "x")
+    assert expr.depth > 200
+
+    assert prune_zeros(expr).depth == expr.depth
+    assert fold_signs(expr).depth == expr.depth
+    assert substitute_value(expr, "x", 0) == value(1)
+    assert to_json(from_json(to_json(expr))) == to_json(expr)
+
+
+def test_validate_wellformed() -> None:
+    x = parameter("x")
+    tag = Expression.Tag
+
+    validate_wellformed(2 * x + sin(x) ** 2 - X(0) * X(1))
+    validate_wellformed(Expression.function("f", 1.5, x))
+
+    malformed = [
+        (Expression(tag.POW, x), []),
+        (Expression.add(x, Expression(tag.ADD)), [1]),
+        (Expression.mul(x, Expression(tag.FN, value(1))), [1]),
+        (Expression(tag.FN, Expression.symbol("f"), "x"), []),
+        (Expression(tag.FN, Expression.symbol("f"), True), []),
+        (Expression.add(x, Expression(tag.VALUE, "1")), [1]),
+        (Expression.kron(X(0), x), []),
+        (Expression(tag.QUANTUM_OP, Expression.symbol("X"), 0), []),
+        (sin(Expression.mul(x, Expression(tag.SYMBOL))), [1, 1]),
+    ]
+
+    for expr, path in malformed:
+        with pytest.raises(SyntaxError, match=re.escape(f"at {path}")):
+            validate_wellformed(expr)